@@ -0,0 +1,152 @@
+use near_sdk::{env, log, near, require, serde_json, AccountId, BorshStorageKey, Gas};
+use near_sdk_contract_tools::owner::*;
+use near_sdk_contract_tools::rbac::Rbac;
+
+use crate::{
+    Contract, ContractExt, CALLBACK_GAS, FT_TRANSFER_GAS, FT_WITHDRAW_GAS, MT_TRANSFER_GAS,
+    NFT_TRANSFER_GAS,
+};
+
+/// Least gas `set_gas_config` accepts for a call, so a typo cannot leave every call short of gas.
+pub const MIN_CALL_GAS: Gas = Gas::from_tgas(5);
+/// Least gas `set_gas_config` accepts for `ft_withdraw`, which also calls the bridged token.
+pub const MIN_FT_WITHDRAW_GAS: Gas = Gas::from_tgas(20);
+
+#[derive(BorshStorageKey)]
+#[near(serializers = [borsh, json])]
+pub enum Role {
+    /// Can change the contract configuration.
+    Admin,
+    /// Can pause and unpause deposits and withdrawals.
+    Pauser,
+    /// Can run maintenance tasks.
+    Operator,
+    /// Can fund reward pools.
+    RewardFunder,
+}
+
+/// Gas attached to the cross contract calls made by the contract.
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct GasConfig {
+    pub mt_transfer: Gas,
+    pub ft_withdraw: Gas,
+    pub ft_transfer: Gas,
+    pub nft_transfer: Gas,
+    pub callback: Gas,
+}
+
+impl Default for GasConfig {
+    fn default() -> Self {
+        Self {
+            mt_transfer: MT_TRANSFER_GAS,
+            ft_withdraw: FT_WITHDRAW_GAS,
+            ft_transfer: FT_TRANSFER_GAS,
+            nft_transfer: NFT_TRANSFER_GAS,
+            callback: CALLBACK_GAS,
+        }
+    }
+}
+
+#[near]
+impl Contract {
+    /// Gives an account a role. Only callable by the owner.
+    pub fn grant_role(&mut self, account_id: AccountId, role: Role) -> bool {
+        Self::require_owner();
+        self.add_role(&account_id, &role)
+    }
+
+    /// Takes a role from an account. Only callable by the owner.
+    pub fn revoke_role(&mut self, account_id: AccountId, role: Role) -> bool {
+        Self::require_owner();
+        self.remove_role(&account_id, &role)
+    }
+
+    pub fn set_intents_contract_id(&mut self, intents_contract_id: AccountId) {
+        self.assert_owner_or_role(&Role::Admin);
+        log!(
+            "Intents contract changed from {} to {}",
+            self.intents_contract_id,
+            intents_contract_id
+        );
+        self.intents_contract_id = intents_contract_id;
+    }
+
+    /// Updates the gas attached to cross contract calls, fields left out keep their value. Values
+    /// below `MIN_CALL_GAS`, or `MIN_FT_WITHDRAW_GAS` for `ft_withdraw`, are rejected.
+    pub fn set_gas_config(
+        &mut self,
+        mt_transfer: Option<Gas>,
+        ft_withdraw: Option<Gas>,
+        ft_transfer: Option<Gas>,
+        nft_transfer: Option<Gas>,
+        callback: Option<Gas>,
+    ) {
+        self.assert_owner_or_role(&Role::Admin);
+        let mut gas = self.gas.clone();
+        if let Some(mt_transfer) = mt_transfer {
+            gas.mt_transfer = mt_transfer;
+        }
+        if let Some(ft_withdraw) = ft_withdraw {
+            gas.ft_withdraw = ft_withdraw;
+        }
+        if let Some(ft_transfer) = ft_transfer {
+            gas.ft_transfer = ft_transfer;
+        }
+        if let Some(nft_transfer) = nft_transfer {
+            gas.nft_transfer = nft_transfer;
+        }
+        if let Some(callback) = callback {
+            gas.callback = callback;
+        }
+
+        require!(
+            [
+                gas.mt_transfer,
+                gas.ft_transfer,
+                gas.nft_transfer,
+                gas.callback
+            ]
+            .iter()
+            .all(|call_gas| *call_gas >= MIN_CALL_GAS),
+            "Gas is below the minimum for a call"
+        );
+        require!(
+            gas.ft_withdraw >= MIN_FT_WITHDRAW_GAS,
+            "Gas is below the minimum for ft_withdraw"
+        );
+
+        log!(
+            "Gas config changed from {} to {}",
+            serde_json::to_string(&self.gas).unwrap(),
+            serde_json::to_string(&gas).unwrap()
+        );
+        self.gas = gas;
+    }
+
+    pub fn get_intents_contract_id(&self) -> AccountId {
+        self.intents_contract_id.clone()
+    }
+
+    pub fn get_gas_config(&self) -> GasConfig {
+        self.gas.clone()
+    }
+
+    pub fn account_has_role(&self, account_id: AccountId, role: Role) -> bool {
+        Self::has_role(&account_id, &role)
+    }
+}
+
+impl Contract {
+    /// Panics unless the caller is the owner or has `role`.
+    pub(crate) fn assert_owner_or_role(&self, role: &Role) {
+        require!(
+            self.is_owner_or_role(&env::predecessor_account_id(), role),
+            "Unauthorized"
+        );
+    }
+
+    pub(crate) fn is_owner_or_role(&self, account_id: &AccountId, role: &Role) -> bool {
+        self.own_get_owner().as_ref() == Some(account_id) || Self::has_role(account_id, role)
+    }
+}
@@ -0,0 +1,171 @@
+use near_sdk::json_types::{U128, U64};
+use near_sdk::{near, AccountId};
+
+/// NEP-297 events emitted by the contract, logged as `EVENT_JSON:{...}`.
+#[near(event_json(standard = "intents_deposit"))]
+pub enum ContractEvent {
+    /// Tokens were credited to an account.
+    #[event_version("1.0.0")]
+    Deposit {
+        account_id: AccountId,
+        sender_id: AccountId,
+        token_id: String,
+        amount: U128,
+    },
+    /// Tokens left an account's balance and are being transferred out.
+    #[event_version("1.0.0")]
+    WithdrawalStarted {
+        nonce: u64,
+        account_id: AccountId,
+        /// NEAR account, or foreign chain address for a bridged withdrawal, receiving the tokens.
+        receiver_id: String,
+        token_id: String,
+        amount: U128,
+    },
+    /// A withdrawal, or part of it, left the contract.
+    #[event_version("1.0.0")]
+    WithdrawalCompleted {
+        nonce: u64,
+        account_id: AccountId,
+        token_id: String,
+        amount: U128,
+    },
+    /// A withdrawal, or part of it, failed and was restored to the account's balance.
+    #[event_version("1.0.0")]
+    WithdrawalReverted {
+        nonce: u64,
+        account_id: AccountId,
+        token_id: String,
+        amount: U128,
+    },
+    /// Tokens moved between two accounts inside the contract.
+    #[event_version("1.0.0")]
+    Transfer {
+        sender_id: AccountId,
+        receiver_id: AccountId,
+        token_id: String,
+        amount: U128,
+        memo: Option<String>,
+    },
+    /// Part of a deposit was locked until `unlock_at`.
+    #[event_version("1.0.0")]
+    Locked {
+        account_id: AccountId,
+        token_id: String,
+        amount: U128,
+        unlock_at: U64,
+    },
+    /// Tokens moved from a funder's balance into a vesting schedule.
+    #[event_version("1.0.0")]
+    VestingCreated {
+        schedule_id: u64,
+        funder_id: AccountId,
+        beneficiary_id: AccountId,
+        token_id: String,
+        amount: U128,
+    },
+    /// Vested tokens were claimed, the withdrawal that sends them follows.
+    #[event_version("1.0.0")]
+    VestingClaimed {
+        schedule_id: u64,
+        beneficiary_id: AccountId,
+        amount: U128,
+    },
+    /// A schedule was revoked and its unvested amount returned to the funder's balance.
+    #[event_version("1.0.0")]
+    VestingRevoked {
+        schedule_id: u64,
+        funder_id: AccountId,
+        amount: U128,
+    },
+    /// Rewards were added to the pool of a staked token.
+    #[event_version("1.0.0")]
+    RewardsFunded {
+        funder_id: AccountId,
+        staked_token_id: String,
+        reward_token_id: String,
+        amount: U128,
+    },
+    /// Rewards were claimed, the withdrawal that sends them follows.
+    #[event_version("1.0.0")]
+    RewardsClaimed {
+        account_id: AccountId,
+        staked_token_id: String,
+        reward_token_id: String,
+        amount: U128,
+    },
+}
+
+/// NEP-245 events for the receipt tokens that represent locked balances.
+#[near(event_json(standard = "nep245"))]
+pub enum Nep245Event {
+    #[event_version("1.0.0")]
+    MtMint(Vec<MtMint>),
+    #[event_version("1.0.0")]
+    MtBurn(Vec<MtBurn>),
+    #[event_version("1.0.0")]
+    MtTransfer(Vec<MtTransfer>),
+}
+
+#[near(serializers = [json])]
+pub struct MtMint {
+    pub owner_id: AccountId,
+    pub token_ids: Vec<String>,
+    pub amounts: Vec<U128>,
+    pub memo: Option<String>,
+}
+
+#[near(serializers = [json])]
+pub struct MtBurn {
+    pub owner_id: AccountId,
+    pub token_ids: Vec<String>,
+    pub amounts: Vec<U128>,
+    pub memo: Option<String>,
+}
+
+#[near(serializers = [json])]
+pub struct MtTransfer {
+    pub old_owner_id: AccountId,
+    pub new_owner_id: AccountId,
+    pub token_ids: Vec<String>,
+    pub amounts: Vec<U128>,
+    pub memo: Option<String>,
+}
+
+impl Nep245Event {
+    /// Receipts minted for tokens credited to an account.
+    pub fn mint(owner_id: &AccountId, token_id: &str, amount: u128) -> Self {
+        Self::MtMint(vec![MtMint {
+            owner_id: owner_id.clone(),
+            token_ids: vec![token_id.to_string()],
+            amounts: vec![U128(amount)],
+            memo: None,
+        }])
+    }
+
+    /// Receipts burned for tokens leaving an account's balance.
+    pub fn burn(owner_id: &AccountId, token_id: &str, amount: u128) -> Self {
+        Self::MtBurn(vec![MtBurn {
+            owner_id: owner_id.clone(),
+            token_ids: vec![token_id.to_string()],
+            amounts: vec![U128(amount)],
+            memo: None,
+        }])
+    }
+
+    pub fn transfer(
+        old_owner_id: &AccountId,
+        new_owner_id: &AccountId,
+        token_id: &str,
+        amount: u128,
+        memo: Option<String>,
+    ) -> Self {
+        Self::MtTransfer(vec![MtTransfer {
+            old_owner_id: old_owner_id.clone(),
+            new_owner_id: new_owner_id.clone(),
+            token_ids: vec![token_id.to_string()],
+            amounts: vec![U128(amount)],
+            memo,
+        }])
+    }
+}
@@ -0,0 +1,7 @@
+use near_sdk::{ext_contract, json_types::U128, AccountId};
+
+#[allow(dead_code)]
+#[ext_contract(ft_contract)]
+trait FT {
+    fn ft_transfer(&self, receiver_id: AccountId, amount: U128, memo: Option<String>);
+}
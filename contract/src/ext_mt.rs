@@ -1,7 +1,29 @@
-use near_sdk::{ext_contract, json_types::U128, AccountId};
+use near_sdk::{ext_contract, json_types::U128, AccountId, PromiseOrValue};
 
 #[allow(dead_code)]
 #[ext_contract(mt_contract)]
 trait MT {
     fn mt_transfer(&self, receiver_id: AccountId, token_id: String, amount: U128);
+    fn mt_batch_transfer(&self, receiver_id: AccountId, token_ids: Vec<String>, amounts: Vec<U128>);
+    fn mt_batch_balance_of(&self, account_id: AccountId, token_ids: Vec<String>) -> Vec<U128>;
+    fn ft_withdraw(
+        &self,
+        token: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+        memo: Option<String>,
+    ) -> U128;
+}
+
+#[allow(dead_code)]
+#[ext_contract(mt_receiver)]
+trait MTReceiver {
+    fn mt_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_ids: Vec<AccountId>,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>>;
 }
@@ -0,0 +1,13 @@
+use near_sdk::{ext_contract, AccountId};
+
+#[allow(dead_code)]
+#[ext_contract(nft_contract)]
+trait NFT {
+    fn nft_transfer(
+        &self,
+        receiver_id: AccountId,
+        token_id: String,
+        approval_id: Option<u64>,
+        memo: Option<String>,
+    );
+}
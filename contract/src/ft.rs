@@ -0,0 +1,139 @@
+use near_sdk::json_types::U128;
+use near_sdk::{
+    env, log, near, require, AccountId, NearToken, Promise, PromiseError, PromiseOrValue,
+};
+use near_sdk_contract_tools::ft::Nep141Receiver;
+
+use crate::events::{ContractEvent, Nep245Event};
+use crate::ext_ft::*;
+use crate::msg::{TransferAction, TransferMessage};
+use crate::token_id::TokenId;
+use crate::{Contract, ContractExt};
+
+#[near]
+impl Nep141Receiver for Contract {
+    fn ft_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        amount: U128,
+        msg: String,
+    ) -> PromiseOrValue<U128> {
+        let token = env::predecessor_account_id();
+        require!(amount.0 > 0, "Cannot deposit 0 tokens");
+
+        if !self.deposits_allowed() {
+            log!("Deposits are paused, refunding transfer");
+            return PromiseOrValue::Value(amount);
+        }
+
+        let Some(message) = TransferMessage::parse(&msg) else {
+            log!("Refunding transfer with an invalid message");
+            return PromiseOrValue::Value(amount);
+        };
+
+        // A lock is only taken for the sender, or anyone could fill a beneficiary's lots
+        if message.action.locks_for_other(&sender_id) {
+            log!("Refunding transfer locked for another account");
+            return PromiseOrValue::Value(amount);
+        }
+
+        let (beneficiary, unlock_at) = match message.action {
+            TransferAction::Deposit {
+                beneficiary,
+                lock_duration,
+            } => (beneficiary, TransferMessage::unlock_at(lock_duration)),
+            TransferAction::FundRewards { .. } => {
+                log!("Refunding transfer, rewards are funded with intents tokens");
+                return PromiseOrValue::Value(amount);
+            }
+        };
+        let account_id = beneficiary.unwrap_or_else(|| sender_id.clone());
+
+        // Kept apart from the nep141: Id of the same token wrapped in intents.near, as the
+        // contract holds the two in different places
+        let token_id = TokenId::Ft { contract_id: token }.to_string();
+
+        if let Err(reason) = self.check_deposit(&token_id, amount.0) {
+            log!("Refunding {} of token {}, {}", amount.0, token_id, reason);
+            return PromiseOrValue::Value(amount);
+        }
+
+        if !self.internal_has_storage_for(&account_id, &token_id) {
+            log!(
+                "Refunding {} of token {}, {} has not registered enough storage",
+                amount.0,
+                token_id,
+                account_id
+            );
+            return PromiseOrValue::Value(amount);
+        }
+
+        if unlock_at.is_some() {
+            if let Err(reason) = self.check_lot(&account_id, &token_id) {
+                log!("Refunding {} of token {}, {}", amount.0, token_id, reason);
+                return PromiseOrValue::Value(amount);
+            }
+        }
+
+        // Accept the deposit up to the token's caps and refund the excess
+        let accepted = self.internal_capped_amount(&account_id, &token_id, amount.0);
+        if accepted == 0 {
+            log!(
+                "Refunding {} of token {}, the deposit cap is reached",
+                amount.0,
+                token_id
+            );
+            return PromiseOrValue::Value(amount);
+        }
+
+        self.internal_deposit(&account_id, &token_id, accepted);
+        self.internal_increase_total(&token_id, accepted);
+
+        Nep245Event::mint(&account_id, &token_id, accepted).emit();
+        if let Some(unlock_at) = unlock_at {
+            self.internal_add_lot(&account_id, &token_id, accepted, unlock_at);
+        }
+        ContractEvent::Deposit {
+            account_id,
+            sender_id,
+            token_id,
+            amount: U128(accepted),
+        }
+        .emit();
+
+        PromiseOrValue::Value(U128(amount.0 - accepted))
+    }
+}
+
+#[near]
+impl Contract {
+    #[private]
+    pub fn ft_transfer_callback(
+        &mut self,
+        #[callback_result] call_result: Result<(), PromiseError>,
+        nonce: u64,
+    ) -> U128 {
+        self.internal_resolve_withdrawal(nonce, call_result.is_ok())
+    }
+}
+
+impl Contract {
+    /// Sends the amount of a pending withdrawal straight from the token contract with `ft_transfer`.
+    pub(crate) fn internal_ft_transfer(
+        &self,
+        token: AccountId,
+        receiver_id: AccountId,
+        amount: u128,
+        nonce: u64,
+    ) -> Promise {
+        ft_contract::ext(token)
+            .with_attached_deposit(NearToken::from_yoctonear(1))
+            .with_static_gas(self.gas.ft_transfer)
+            .ft_transfer(receiver_id, U128(amount), None)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(self.gas.callback)
+                    .ft_transfer_callback(nonce),
+            )
+    }
+}
@@ -0,0 +1,160 @@
+use near_sdk::json_types::U128;
+use near_sdk::{env, log, near, require, AccountId, Gas, NearToken, Promise, PromiseError};
+use near_sdk_contract_tools::owner::*;
+
+use crate::ext_mt::*;
+use crate::token_id::TokenId;
+use crate::{Contract, ContractExt};
+
+/// Gas attached to the `mt_batch_balance_of` call on the intents contract.
+pub const BALANCE_OF_GAS: Gas = Gas::from_tgas(10);
+
+/// What the contract holds of a token in the intents contract against what it owes its accounts.
+#[near(serializers = [json])]
+pub struct TokenReconciliation {
+    pub token_id: String,
+    pub held: U128,
+    pub liabilities: U128,
+    pub surplus: U128,
+    pub deficit: U128,
+}
+
+#[near]
+impl Contract {
+    /// Amount of a fungible token the contract owes its accounts, pending withdrawals included.
+    pub fn get_token_liabilities(&self, token_id: TokenId) -> U128 {
+        U128(self.internal_token_total(&token_id.to_string()))
+    }
+
+    /// Compares the balance of each token the contract holds in the intents contract with its
+    /// liabilities, reporting the surplus or deficit of each. Withdrawals in flight show up as a
+    /// deficit until they settle.
+    pub fn reconcile_tokens(&self, token_ids: Vec<TokenId>) -> Promise {
+        require!(!token_ids.is_empty(), "No tokens to reconcile");
+        require!(
+            token_ids
+                .iter()
+                .all(|token_id| token_id.is_intents() && !token_id.is_nft()),
+            "Only fungible intents tokens can be reconciled"
+        );
+        let token_ids: Vec<String> = token_ids.iter().map(ToString::to_string).collect();
+
+        mt_contract::ext(self.intents_contract_id.clone())
+            .with_static_gas(BALANCE_OF_GAS)
+            .mt_batch_balance_of(env::current_account_id(), token_ids.clone())
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(self.gas.callback)
+                    .reconcile_tokens_callback(token_ids),
+            )
+    }
+
+    #[private]
+    pub fn reconcile_tokens_callback(
+        &self,
+        #[callback_result] call_result: Result<Vec<U128>, PromiseError>,
+        token_ids: Vec<String>,
+    ) -> Vec<TokenReconciliation> {
+        let balances =
+            call_result.unwrap_or_else(|_| panic!("Failed to read balances from intents"));
+        require!(
+            balances.len() == token_ids.len(),
+            "Unexpected number of balances"
+        );
+
+        token_ids
+            .into_iter()
+            .zip(balances)
+            .map(|(token_id, held)| {
+                let liabilities = self.internal_token_total(&token_id);
+                TokenReconciliation {
+                    token_id,
+                    held,
+                    liabilities: U128(liabilities),
+                    surplus: U128(held.0.saturating_sub(liabilities)),
+                    deficit: U128(liabilities.saturating_sub(held.0)),
+                }
+            })
+            .collect()
+    }
+
+    /// Sends `amount` of a token (all of its surplus by default) held in the intents contract
+    /// above what the contract owes its accounts to `receiver_id`, for example tokens sent with
+    /// a plain `mt_transfer`. Only callable by the owner, and only once the token totals are
+    /// complete.
+    ///
+    /// A deposit made with `mt_transfer_call` is already in the contract's intents balance while
+    /// `mt_on_transfer` has not credited it yet, and tokens it refunds stay there until the
+    /// intents contract resolves the transfer. Such a deposit reads as surplus, so pause deposits
+    /// and let the transfers in flight resolve, or rescue no more than `reconcile_tokens`
+    /// reported as surplus over a few blocks, before rescuing tokens.
+    pub fn rescue_surplus(
+        &mut self,
+        token_id: TokenId,
+        receiver_id: AccountId,
+        amount: Option<U128>,
+    ) -> Promise {
+        Self::require_owner();
+        require!(
+            self.totals_complete,
+            "Token totals are incomplete until every account is migrated"
+        );
+        require!(
+            token_id.is_intents() && !token_id.is_nft(),
+            "Only fungible intents tokens can be rescued"
+        );
+        let token_id = token_id.to_string();
+        let liabilities = self.internal_token_total(&token_id);
+
+        mt_contract::ext(self.intents_contract_id.clone())
+            .with_static_gas(BALANCE_OF_GAS)
+            .mt_batch_balance_of(env::current_account_id(), vec![token_id.clone()])
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(self.gas.callback.saturating_add(self.gas.mt_transfer))
+                    .rescue_surplus_callback(token_id, receiver_id, amount, U128(liabilities)),
+            )
+    }
+
+    #[private]
+    pub fn rescue_surplus_callback(
+        &mut self,
+        #[callback_result] call_result: Result<Vec<U128>, PromiseError>,
+        token_id: String,
+        receiver_id: AccountId,
+        amount: Option<U128>,
+        liabilities: U128,
+    ) -> Promise {
+        let held = call_result
+            .ok()
+            .and_then(|balances| balances.first().copied())
+            .unwrap_or_else(|| panic!("Failed to read balances from intents"));
+
+        // Deposits and withdrawals can settle while the balance is read, so the larger of the
+        // liabilities before and after is kept to never count user funds as surplus
+        let liabilities = liabilities.0.max(self.internal_token_total(&token_id));
+        let surplus = held.0.saturating_sub(liabilities);
+        require!(surplus > 0, "No surplus to rescue");
+        let amount = amount.map(|amount| amount.0).unwrap_or(surplus);
+        require!(amount > 0, "Cannot rescue 0 tokens");
+        require!(amount <= surplus, "The amount is greater than the surplus");
+
+        log!(
+            "Rescuing {} of token {} to {}",
+            amount,
+            token_id,
+            receiver_id
+        );
+
+        mt_contract::ext(self.intents_contract_id.clone())
+            .with_attached_deposit(NearToken::from_yoctonear(1))
+            .with_static_gas(self.gas.mt_transfer)
+            .mt_transfer(receiver_id, token_id, U128(amount))
+    }
+}
+
+impl Contract {
+    pub(crate) fn internal_token_total(&self, token_id: &str) -> u128 {
+        self.token_totals.get(token_id).copied().unwrap_or(0)
+    }
+}
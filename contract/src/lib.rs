@@ -1,29 +1,134 @@
-use near_sdk::json_types::U128;
-use near_sdk::store::{IterableMap, LookupMap};
+use near_sdk::json_types::{U128, U64};
+use near_sdk::store::{IterableMap, IterableSet, LookupMap};
 use near_sdk::{
-    env, log, near, require, AccountId, BorshStorageKey, Gas, NearToken, PanicOnDefault, Promise,
-    PromiseError, PromiseOrValue,
+    assert_one_yocto, env, log, near, require, AccountId, BorshStorageKey, CryptoHash, Gas,
+    NearToken, PanicOnDefault, Promise, PromiseError, PromiseOrValue,
 };
 use near_sdk_contract_tools::mt::Nep245Receiver;
+use near_sdk_contract_tools::{owner::*, Owner, Rbac};
 
+pub mod admin;
+pub mod events;
+pub mod ext_ft;
 pub mod ext_mt;
+pub mod ext_nft;
+pub mod ft;
+pub mod liabilities;
+pub mod msg;
+pub mod nft;
+pub mod pause;
+pub mod receipt;
+pub mod registry;
+pub mod rewards;
+pub mod storage;
+pub mod timelock;
+pub mod token_id;
+pub mod transfer;
+pub mod upgrade;
+pub mod vesting;
+use crate::admin::{GasConfig, Role};
+use crate::events::{ContractEvent, Nep245Event};
 use crate::ext_mt::*;
+use crate::msg::{TransferAction, TransferMessage};
+use crate::pause::PauseState;
+use crate::registry::TokenConfig;
+use crate::rewards::{RewardPool, RewardStake};
+use crate::storage::AccountStorage;
+use crate::timelock::Lot;
+use crate::token_id::{is_nft_token_id, TokenId};
+use crate::upgrade::{write_state_version, StateVersion};
+use crate::vesting::VestingSchedule;
 
 #[near(contract_state)]
-#[derive(PanicOnDefault)]
+#[derive(PanicOnDefault, Owner, Rbac)]
+#[rbac(roles = "Role")]
 pub struct Contract {
     intents_contract_id: AccountId,
+    gas: GasConfig,
+    pause: PauseState,
     balances: LookupMap<AccountId, IterableMap<String, u128>>,
+    pending_balances: LookupMap<(AccountId, String), u128>,
+    pending_withdrawals: LookupMap<u64, PendingWithdrawal>,
+    next_withdrawal_nonce: u64,
+    storage_deposits: LookupMap<AccountId, AccountStorage>,
+    /// Vesting schedules each account is the beneficiary of plus its withdrawals in flight,
+    /// which keep it from unregistering.
+    account_commitments: LookupMap<AccountId, u32>,
+    token_registry: IterableMap<String, TokenConfig>,
+    token_totals: LookupMap<String, u128>,
+    totals_complete: bool,
+    nfts: LookupMap<AccountId, IterableSet<String>>,
+    locks: LookupMap<(AccountId, String), Vec<Lot>>,
+    vestings: LookupMap<u64, VestingSchedule>,
+    next_vesting_id: u64,
+    reward_pools: IterableMap<String, RewardPool>,
+    reward_stakes: LookupMap<(AccountId, String), RewardStake>,
 }
 
+/// Prefixes of the contract's collections, each being the variant's discriminant. New keys are
+/// only ever appended and removed keys stay in place, so deployed collections keep their prefix.
 #[derive(BorshStorageKey)]
 #[near]
 pub enum StorageKey {
     Balances,
+    PendingBalances,
+    PendingWithdrawals,
+    StorageDeposits,
+    StateVersion,
+    AccountBalances {
+        account_hash: CryptoHash,
+    },
+    /// Held the NEP-141 allowlist, replaced by the token registry. Not used anymore.
+    FtAllowlist,
+    Nfts,
+    AccountNfts {
+        account_hash: CryptoHash,
+    },
+    TokenRegistry,
+    TokenTotals,
+    Locks,
+    Vestings,
+    RewardPools,
+    RewardStakes,
+    AccountCommitments,
+}
+
+/// Storage key of an account's balances map. Hashing the account Id keeps every prefix the same
+/// length, so no account's keys can overlap with another's.
+pub(crate) fn account_balances_key(account_id: &AccountId) -> StorageKey {
+    StorageKey::AccountBalances {
+        account_hash: env::sha256_array(account_id.as_bytes()),
+    }
+}
+
+/// Memo of an `ft_withdraw` call, the POA bridge picks the destination address up from it.
+fn withdraw_to_memo(address: &str, memo: Option<String>) -> String {
+    match memo {
+        Some(memo) => format!("WITHDRAW_TO:{}:{}", address, memo),
+        None => format!("WITHDRAW_TO:{}", address),
+    }
+}
+
+/// A withdrawal that has left the account's available balance but has not been settled yet.
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct PendingWithdrawal {
+    pub nonce: u64,
+    pub account_id: AccountId,
+    /// NEAR account the tokens are sent to, or the foreign chain address for a withdrawal
+    /// bridged with `withdraw_to_chain`.
+    pub receiver_id: String,
+    pub token_id: String,
+    pub amount: U128,
+    pub created_at: U64,
 }
 
 pub const MT_TRANSFER_GAS: Gas = Gas::from_tgas(10);
 pub const CALLBACK_GAS: Gas = Gas::from_tgas(10);
+pub const FT_WITHDRAW_GAS: Gas = Gas::from_tgas(50);
+pub const FT_TRANSFER_GAS: Gas = Gas::from_tgas(10);
+pub const NFT_TRANSFER_GAS: Gas = Gas::from_tgas(10);
+pub const MAX_BATCH_WITHDRAWALS: usize = 10;
 
 #[near]
 impl Nep245Receiver for Contract {
@@ -35,55 +140,152 @@ impl Nep245Receiver for Contract {
         amounts: Vec<U128>,
         msg: String,
     ) -> PromiseOrValue<Vec<U128>> {
-        let _ = sender_id;
-        let _ = msg;
-
-        require!(
-            token_ids.len() == 1,
-            "This contract only accepts one token at a time"
-        );
+        require!(!token_ids.is_empty(), "No tokens transferred");
         require!(
-            previous_owner_ids.len() == 1 && amounts.len() == 1,
+            previous_owner_ids.len() == token_ids.len() && amounts.len() == token_ids.len(),
             "Invalid input length"
         );
         require!(
             env::predecessor_account_id() == self.intents_contract_id,
             "Only accepts the intents.near multi-token contract"
         );
+        require!(
+            amounts.iter().all(|amount| amount.0 > 0),
+            "Cannot deposit 0 tokens"
+        );
 
-        let token_id = &token_ids[0];
-        let previous_owner_id = &previous_owner_ids[0];
-        let amount = &amounts[0];
+        if !self.deposits_allowed() {
+            log!("Deposits are paused, refunding transfer");
+            return PromiseOrValue::Value(amounts);
+        }
 
-        require!(amount.0 > 0, "Cannot deposit 0 tokens");
+        // Refund the whole transfer rather than crediting it to the wrong account
+        let Some(message) = TransferMessage::parse(&msg) else {
+            log!("Refunding transfer with an invalid message");
+            return PromiseOrValue::Value(amounts);
+        };
 
-        // If the previous owner has no tokens, create a new map for them
-        if self.balances.get(previous_owner_id).is_none() {
-            let new_map: IterableMap<String, u128> = IterableMap::new(previous_owner_id.as_bytes());
-            self.balances.insert(previous_owner_id.clone(), new_map);
+        // A lock is only taken for the sender, or anyone could fill a beneficiary's lots
+        if message.action.locks_for_other(&sender_id) {
+            log!("Refunding transfer locked for another account");
+            return PromiseOrValue::Value(amounts);
         }
 
-        // Get the current balance of the previous owner for the specific token
-        let tokens = self.balances.get_mut(previous_owner_id).unwrap();
+        let (beneficiary, unlock_at) = match message.action {
+            TransferAction::Deposit {
+                beneficiary,
+                lock_duration,
+            } => (beneficiary, TransferMessage::unlock_at(lock_duration)),
+            TransferAction::FundRewards { staked_token_id } => {
+                return PromiseOrValue::Value(self.internal_fund_rewards(
+                    &sender_id,
+                    &token_ids,
+                    &amounts,
+                    staked_token_id,
+                ));
+            }
+        };
+
+        // Credit every token in the batch to the beneficiary, or to its previous owner
+        let mut refunds = Vec::with_capacity(token_ids.len());
+        for ((token_id, previous_owner_id), amount) in token_ids
+            .iter()
+            .zip(previous_owner_ids.iter())
+            .zip(amounts.iter())
+        {
+            let account_id = beneficiary.as_ref().unwrap_or(previous_owner_id);
 
-        match tokens.get(token_id) {
-            None => {
-                // Token entry doesn't exist yet, set it to the amount
-                tokens.insert(token_id.clone(), amount.0);
+            // Refund tokens with an Id the contract does not understand
+            let Some(parsed_id) = token_id.parse::<TokenId>().ok().filter(TokenId::is_intents)
+            else {
+                log!(
+                    "Refunding {} of token {}, the token Id is invalid",
+                    amount.0,
+                    token_id
+                );
+                refunds.push(*amount);
+                continue;
+            };
+
+            // Refund tokens and NFT collections that are not listed in the registry
+            let check = if parsed_id.is_nft() {
+                self.check_nft_deposit(&parsed_id)
+            } else {
+                self.check_deposit(token_id, amount.0)
+            };
+            if let Err(reason) = check {
+                log!("Refunding {} of token {}, {}", amount.0, token_id, reason);
+                refunds.push(*amount);
+                continue;
             }
-            Some(current_amount) => {
-                // Token entry exists, check if it's zero
-                require!(
-                    *current_amount != 0u128,
-                    "Cannot deposit while withdrawal is in progress"
+
+            // Refund tokens the account has not paid storage for
+            if !self.internal_has_storage_for(account_id, token_id) {
+                log!(
+                    "Refunding {} of token {}, {} has not registered enough storage",
+                    amount.0,
+                    token_id,
+                    account_id
                 );
-                tokens.insert(token_id.clone(), current_amount + amount.0);
+                refunds.push(*amount);
+                continue;
+            }
+
+            // Refund locked deposits that cannot add a lot
+            if unlock_at.is_some() {
+                let check = if parsed_id.is_nft() {
+                    Err("NFTs cannot be locked")
+                } else {
+                    self.check_lot(account_id, token_id)
+                };
+                if let Err(reason) = check {
+                    log!("Refunding {} of token {}, {}", amount.0, token_id, reason);
+                    refunds.push(*amount);
+                    continue;
+                }
             }
-        }
 
-        log!("Deposited {} of token {}", amount.0, token_id);
+            // Wrapped NFTs are tracked apart from the fungible balances
+            let accepted = if parsed_id.is_nft() {
+                if amount.0 != 1 {
+                    log!("Refunding {} of NFT {}", amount.0, token_id);
+                    refunds.push(*amount);
+                    continue;
+                }
+                self.internal_deposit_nft(account_id, token_id);
+                1
+            } else {
+                // Accept the deposit up to the token's caps and refund the excess
+                let accepted = self.internal_capped_amount(account_id, token_id, amount.0);
+                if accepted == 0 {
+                    log!(
+                        "Refunding {} of token {}, the deposit cap is reached",
+                        amount.0,
+                        token_id
+                    );
+                    refunds.push(*amount);
+                    continue;
+                }
+                self.internal_deposit(account_id, token_id, accepted);
+                self.internal_increase_total(token_id, accepted);
+                Nep245Event::mint(account_id, token_id, accepted).emit();
+                if let Some(unlock_at) = unlock_at {
+                    self.internal_add_lot(account_id, token_id, accepted, unlock_at);
+                }
+                accepted
+            };
+
+            ContractEvent::Deposit {
+                account_id: account_id.clone(),
+                sender_id: sender_id.clone(),
+                token_id: token_id.clone(),
+                amount: U128(accepted),
+            }
+            .emit();
+            refunds.push(U128(amount.0 - accepted));
+        }
 
-        PromiseOrValue::Value(vec![U128(0)])
+        PromiseOrValue::Value(refunds)
     }
 }
 
@@ -91,36 +293,181 @@ impl Nep245Receiver for Contract {
 impl Contract {
     #[init]
     #[private]
-    pub fn new(intents_contract_id: AccountId) -> Self {
-        Self {
-            intents_contract_id,
-            balances: LookupMap::new(StorageKey::Balances),
+    pub fn new(intents_contract_id: AccountId, owner_id: Option<AccountId>) -> Self {
+        let mut contract = Self::internal_new(intents_contract_id);
+
+        // The contract account owns itself unless another owner is given
+        let owner_id = owner_id.unwrap_or_else(env::current_account_id);
+        Owner::init(&mut contract, &owner_id);
+        write_state_version(StateVersion::CURRENT);
+
+        contract
+    }
+
+    /// Withdraws `amount` of a token to `receiver_id` (the caller by default), or the whole balance if no amount is given.
+    /// The amount is moved to a pending withdrawal until the transfer settles, the rest of the balance stays usable.
+    /// Requires 1 yoctoNEAR, as the tokens can be sent to another account.
+    #[payable]
+    pub fn withdraw_token(
+        &mut self,
+        token_id: TokenId,
+        amount: Option<U128>,
+        receiver_id: Option<AccountId>,
+    ) -> Promise {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        let receiver_id = receiver_id.unwrap_or_else(|| account_id.clone());
+        require!(!token_id.is_nft(), "NFTs are withdrawn with withdraw_nft");
+        self.assert_withdrawal_allowed(&account_id, &receiver_id, false);
+
+        let ledger_id = token_id.to_string();
+        let balance = self
+            .internal_available_balance(&account_id, &ledger_id)
+            .unwrap_or(0);
+        require!(balance > 0, "Token balance is zero");
+        let balance = balance - self.internal_locked_amount(&account_id, &ledger_id);
+        require!(balance > 0, "The balance is still locked");
+        let amount = amount.map(|amount| amount.0).unwrap_or(balance);
+        require!(amount > 0, "Cannot withdraw 0 tokens");
+        self.assert_min_withdrawal(&ledger_id, amount, balance);
+
+        // Move the amount from the available balance to a pending withdrawal
+        self.internal_withdraw(&account_id, &ledger_id, amount);
+        let nonce =
+            self.internal_start_withdrawal(&account_id, receiver_id.as_str(), &ledger_id, amount);
+
+        // Tokens locked with ft_transfer_call are sent back by their own contract
+        if let TokenId::Ft { contract_id } = token_id {
+            return self.internal_ft_transfer(contract_id, receiver_id, amount, nonce);
         }
+
+        // Transfer the amount of the token to the receiver
+        mt_contract::ext(self.intents_contract_id.clone())
+            .with_attached_deposit(NearToken::from_yoctonear(1))
+            .with_static_gas(self.gas.mt_transfer)
+            .mt_transfer(receiver_id, ledger_id, U128(amount))
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(self.gas.callback)
+                    .withdraw_callback(nonce),
+            )
     }
 
-    pub fn withdraw_token(&mut self, token_id: String) -> Promise {
+    /// Withdraws several tokens to `receiver_id` (the caller by default) with a single `mt_batch_transfer`.
+    /// Requires 1 yoctoNEAR, like `withdraw_token`.
+    #[payable]
+    pub fn withdraw_tokens(
+        &mut self,
+        tokens: Vec<(TokenId, U128)>,
+        receiver_id: Option<AccountId>,
+    ) -> Promise {
+        assert_one_yocto();
         let account_id = env::predecessor_account_id();
+        let receiver_id = receiver_id.unwrap_or_else(|| account_id.clone());
+        self.assert_withdrawal_allowed(&account_id, &receiver_id, false);
 
-        // Update the balance of the account for the specific token to zero
-        let tokens = self
-            .balances
-            .get_mut(&account_id)
-            .unwrap_or_else(|| panic!("No tokens found for account"));
-        let amount = *tokens.get(&token_id).unwrap_or(&0u128);
-        require!(amount > 0, "Token balance is zero");
-        tokens.insert(token_id.clone(), 0);
+        require!(!tokens.is_empty(), "No tokens to withdraw");
+        require!(
+            tokens.len() <= MAX_BATCH_WITHDRAWALS,
+            "Too many tokens in one withdrawal"
+        );
 
-        log!("Withdrawing {} of token {}", amount, token_id);
+        let mut token_ids = Vec::with_capacity(tokens.len());
+        let mut amounts = Vec::with_capacity(tokens.len());
+        let mut nonces = Vec::with_capacity(tokens.len());
+        for (token_id, amount) in tokens {
+            require!(
+                token_id.is_intents() && !token_id.is_nft(),
+                "Only fungible intents tokens can be withdrawn in a batch"
+            );
+            let token_id = token_id.to_string();
+            require!(
+                !token_ids.contains(&token_id),
+                "Duplicate token in withdrawal"
+            );
+            require!(amount.0 > 0, "Cannot withdraw 0 tokens");
+            let balance = self
+                .internal_available_balance(&account_id, &token_id)
+                .unwrap_or(0)
+                .saturating_sub(self.internal_locked_amount(&account_id, &token_id));
+            self.assert_min_withdrawal(&token_id, amount.0, balance);
 
-        // Transfer the full amount of the token to the user
+            // Move each amount from the available balance to its own pending withdrawal
+            self.internal_withdraw(&account_id, &token_id, amount.0);
+            let nonce = self.internal_start_withdrawal(
+                &account_id,
+                receiver_id.as_str(),
+                &token_id,
+                amount.0,
+            );
+
+            token_ids.push(token_id);
+            amounts.push(amount);
+            nonces.push(nonce);
+        }
+
+        // The callback settles every withdrawal of the batch, so it needs gas for each of them
+        let transfer_gas = Gas::from_gas(self.gas.mt_transfer.as_gas() * token_ids.len() as u64);
+        let callback_gas = Gas::from_gas(self.gas.callback.as_gas() * token_ids.len() as u64);
+
+        // Transfer all the tokens to the receiver in one call
+        mt_contract::ext(self.intents_contract_id.clone())
+            .with_attached_deposit(NearToken::from_yoctonear(1))
+            .with_static_gas(transfer_gas)
+            .mt_batch_transfer(receiver_id, token_ids, amounts)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(callback_gas)
+                    .withdraw_tokens_callback(nonces),
+            )
+    }
+
+    /// Unlocks `amount` of a bridged token (the whole balance if no amount is given) and withdraws it
+    /// straight to `address` on its native chain by calling `ft_withdraw` on the intents contract.
+    /// Requires 1 yoctoNEAR, like `withdraw_token`.
+    #[payable]
+    pub fn withdraw_to_chain(
+        &mut self,
+        token_id: TokenId,
+        amount: Option<U128>,
+        address: String,
+        memo: Option<String>,
+    ) -> Promise {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+
+        // Only NEP-141 tokens can be bridged back with ft_withdraw
+        let TokenId::Nep141 { contract_id: token } = token_id.clone() else {
+            panic!("Only nep141 tokens can be withdrawn to another chain");
+        };
+        let token_id = token_id.to_string();
+        require!(!address.is_empty(), "Destination address is empty");
+        self.assert_withdrawal_allowed(&account_id, &account_id, true);
+
+        let balance = self
+            .internal_available_balance(&account_id, &token_id)
+            .unwrap_or(0);
+        require!(balance > 0, "Token balance is zero");
+        let balance = balance - self.internal_locked_amount(&account_id, &token_id);
+        require!(balance > 0, "The balance is still locked");
+        let amount = amount.map(|amount| amount.0).unwrap_or(balance);
+        require!(amount > 0, "Cannot withdraw 0 tokens");
+        self.assert_min_withdrawal(&token_id, amount, balance);
+
+        // Move the amount from the available balance to a pending withdrawal
+        self.internal_withdraw(&account_id, &token_id, amount);
+        let nonce = self.internal_start_withdrawal(&account_id, &address, &token_id, amount);
+        let memo = withdraw_to_memo(&address, memo);
+
+        // Burn the tokens in the intents contract and bridge them to the destination
         mt_contract::ext(self.intents_contract_id.clone())
             .with_attached_deposit(NearToken::from_yoctonear(1))
-            .with_static_gas(MT_TRANSFER_GAS)
-            .mt_transfer(account_id.clone(), token_id.clone(), U128(amount))
+            .with_static_gas(self.gas.ft_withdraw)
+            .ft_withdraw(token.clone(), token, U128(amount), Some(memo))
             .then(
                 Self::ext(env::current_account_id())
-                    .with_static_gas(CALLBACK_GAS)
-                    .withdraw_callback(token_id, U128(amount), account_id),
+                    .with_static_gas(self.gas.callback)
+                    .withdraw_to_chain_callback(nonce),
             )
     }
 
@@ -128,34 +475,38 @@ impl Contract {
     pub fn withdraw_callback(
         &mut self,
         #[callback_result] call_result: Result<(), PromiseError>,
-        token_id: String,
-        amount: U128,
-        account_id: AccountId,
+        nonce: u64,
     ) -> U128 {
-        if call_result.is_ok() {
-            // Remove the token from the map
-            let tokens = self
-                .balances
-                .get_mut(&account_id)
-                .unwrap_or_else(|| panic!("No tokens found for account"));
-            tokens.remove(&token_id);
-
-            if tokens.is_empty() {
-                self.balances.remove(&account_id);
-            }
+        self.internal_resolve_withdrawal(nonce, call_result.is_ok())
+    }
 
-            log!("Token withdrawal successful");
-            return U128(0);
-        } else {
-            // Restore the balance if withdrawal failed
-            let tokens = self
-                .balances
-                .get_mut(&account_id)
-                .unwrap_or_else(|| panic!("No tokens found for account"));
-            tokens.insert(token_id.clone(), amount.0);
-            log!("Token withdrawal failed");
-            return amount;
-        }
+    #[private]
+    pub fn withdraw_tokens_callback(
+        &mut self,
+        #[callback_result] call_result: Result<(), PromiseError>,
+        nonces: Vec<u64>,
+    ) -> Vec<U128> {
+        nonces
+            .into_iter()
+            .map(|nonce| self.internal_resolve_withdrawal(nonce, call_result.is_ok()))
+            .collect()
+    }
+
+    #[private]
+    pub fn withdraw_to_chain_callback(
+        &mut self,
+        #[callback_result] call_result: Result<U128, PromiseError>,
+        nonce: u64,
+    ) -> U128 {
+        let withdrawal = self.internal_settle_withdrawal(nonce);
+
+        // The intents contract resolves the bridge transfer itself and reports how much was withdrawn
+        let refund = match call_result {
+            Ok(withdrawn) => withdrawal.amount.0.saturating_sub(withdrawn.0),
+            Err(_) => withdrawal.amount.0,
+        };
+
+        self.internal_refund_withdrawal(&withdrawal, refund)
     }
 
     pub fn get_tokens_for_account(
@@ -182,10 +533,427 @@ impl Contract {
     pub fn get_token_balance_for_account(
         &self,
         account: AccountId,
-        token_id: String,
+        token_id: TokenId,
+    ) -> Option<U128> {
+        self.internal_available_balance(&account, &token_id.to_string())
+            .map(U128::from)
+    }
+
+    pub fn get_pending_balance_for_account(
+        &self,
+        account: AccountId,
+        token_id: TokenId,
     ) -> Option<U128> {
+        self.pending_balances
+            .get(&(account, token_id.to_string()))
+            .map(|amount| U128::from(*amount))
+    }
+
+    pub fn get_pending_withdrawal(&self, nonce: u64) -> Option<PendingWithdrawal> {
+        self.pending_withdrawals.get(&nonce).cloned()
+    }
+}
+
+impl Contract {
+    /// Fresh state with every collection empty, shared by `new` and migrations.
+    fn internal_new(intents_contract_id: AccountId) -> Self {
+        Self {
+            intents_contract_id,
+            gas: GasConfig::default(),
+            pause: PauseState::default(),
+            balances: LookupMap::new(StorageKey::Balances),
+            pending_balances: LookupMap::new(StorageKey::PendingBalances),
+            pending_withdrawals: LookupMap::new(StorageKey::PendingWithdrawals),
+            next_withdrawal_nonce: 0,
+            storage_deposits: LookupMap::new(StorageKey::StorageDeposits),
+            account_commitments: LookupMap::new(StorageKey::AccountCommitments),
+            token_registry: IterableMap::new(StorageKey::TokenRegistry),
+            token_totals: LookupMap::new(StorageKey::TokenTotals),
+            totals_complete: true,
+            nfts: LookupMap::new(StorageKey::Nfts),
+            locks: LookupMap::new(StorageKey::Locks),
+            vestings: LookupMap::new(StorageKey::Vestings),
+            next_vesting_id: 0,
+            reward_pools: IterableMap::new(StorageKey::RewardPools),
+            reward_stakes: LookupMap::new(StorageKey::RewardStakes),
+        }
+    }
+
+    fn internal_available_balance(&self, account_id: &AccountId, token_id: &str) -> Option<u128> {
         self.balances
-            .get(&account)
-            .and_then(|tokens| tokens.get(&token_id).map(|amount| U128::from(*amount)))
+            .get(account_id)
+            .and_then(|tokens| tokens.get(token_id).copied())
+    }
+
+    /// Adds `amount` of a token to an account's balance, creating the entries and charging
+    /// the storage they use if needed.
+    fn internal_deposit(&mut self, account_id: &AccountId, token_id: &str, amount: u128) {
+        let initial_storage = env::storage_usage();
+
+        // If the account has no tokens, create a new map for them
+        if self.balances.get(account_id).is_none() {
+            let new_map: IterableMap<String, u128> =
+                IterableMap::new(account_balances_key(account_id));
+            self.balances.insert(account_id.clone(), new_map);
+        }
+
+        let tokens = self.balances.get_mut(account_id).unwrap();
+        let current_amount = tokens.get(token_id).copied().unwrap_or(0);
+        tokens.insert(
+            token_id.to_string(),
+            current_amount
+                .checked_add(amount)
+                .unwrap_or_else(|| panic!("Balance overflow")),
+        );
+
+        // Write the entries now, so the storage they use can be measured
+        tokens.flush();
+        self.balances.flush();
+        self.internal_settle_storage(account_id, initial_storage);
+
+        self.internal_sync_stake(account_id, token_id);
+    }
+
+    /// Adds to the amount of a token the contract owes its accounts, pending withdrawals included.
+    fn internal_increase_total(&mut self, token_id: &str, amount: u128) {
+        let total = self.internal_token_total(token_id);
+        self.token_totals.insert(
+            token_id.to_string(),
+            total
+                .checked_add(amount)
+                .unwrap_or_else(|| panic!("Total overflow")),
+        );
+    }
+
+    /// Removes an amount that left the contract from the total owed for a token.
+    fn internal_decrease_total(&mut self, token_id: &str, amount: u128) {
+        let total = self.internal_token_total(token_id);
+        if total <= amount {
+            self.token_totals.remove(token_id);
+        } else {
+            self.token_totals
+                .insert(token_id.to_string(), total - amount);
+        }
+    }
+
+    /// Removes `amount` of a token from an account's balance, dropping entries that reach zero
+    /// and crediting the storage they freed back.
+    fn internal_withdraw(&mut self, account_id: &AccountId, token_id: &str, amount: u128) {
+        // Lots stay in the balance until they unlock
+        self.internal_prune_lots(account_id, token_id);
+        let locked = self.internal_locked_amount(account_id, token_id);
+        let initial_storage = env::storage_usage();
+
+        let tokens = self
+            .balances
+            .get_mut(account_id)
+            .unwrap_or_else(|| panic!("No tokens found for account"));
+        let balance = *tokens.get(token_id).unwrap_or(&0u128);
+        require!(balance > 0, "Token balance is zero");
+        require!(amount <= balance, "Not enough balance to withdraw");
+        require!(amount <= balance - locked, "The amount is still locked");
+
+        if amount == balance {
+            tokens.remove(token_id);
+        } else {
+            tokens.insert(token_id.to_string(), balance - amount);
+        }
+
+        // An empty map still keeps its free slots in storage until it is cleared
+        let emptied = tokens.is_empty();
+        if emptied {
+            tokens.clear();
+        }
+        tokens.flush();
+        if emptied {
+            self.balances.remove(account_id);
+        }
+        self.balances.flush();
+        self.internal_settle_storage(account_id, initial_storage);
+
+        self.internal_sync_stake(account_id, token_id);
+    }
+
+    /// Records a pending withdrawal and returns its nonce.
+    fn internal_start_withdrawal(
+        &mut self,
+        account_id: &AccountId,
+        receiver_id: &str,
+        token_id: &str,
+        amount: u128,
+    ) -> u64 {
+        let nonce = self.next_withdrawal_nonce;
+        self.next_withdrawal_nonce += 1;
+
+        let key = (account_id.clone(), token_id.to_string());
+        let pending = self.pending_balances.get(&key).copied().unwrap_or(0);
+        self.pending_balances.insert(key, pending + amount);
+        self.internal_add_commitment(account_id);
+
+        // The receipts of fungible tokens are burned as they leave the balance
+        if !is_nft_token_id(token_id) {
+            Nep245Event::burn(account_id, token_id, amount).emit();
+        }
+        ContractEvent::WithdrawalStarted {
+            nonce,
+            account_id: account_id.clone(),
+            receiver_id: receiver_id.to_string(),
+            token_id: token_id.to_string(),
+            amount: U128(amount),
+        }
+        .emit();
+
+        self.pending_withdrawals.insert(
+            nonce,
+            PendingWithdrawal {
+                nonce,
+                account_id: account_id.clone(),
+                receiver_id: receiver_id.to_string(),
+                token_id: token_id.to_string(),
+                amount: U128(amount),
+                created_at: U64(env::block_timestamp()),
+            },
+        );
+
+        nonce
+    }
+
+    /// Settles a pending withdrawal, restoring its amount to the available balance if the transfer failed.
+    /// Returns the amount that was restored.
+    fn internal_resolve_withdrawal(&mut self, nonce: u64, transferred: bool) -> U128 {
+        let withdrawal = self.internal_settle_withdrawal(nonce);
+        let refund = if transferred { 0 } else { withdrawal.amount.0 };
+
+        self.internal_refund_withdrawal(&withdrawal, refund)
+    }
+
+    /// Restores the part of a settled withdrawal that did not leave the contract.
+    fn internal_refund_withdrawal(&mut self, withdrawal: &PendingWithdrawal, refund: u128) -> U128 {
+        let transferred = withdrawal.amount.0 - refund;
+        if transferred > 0 {
+            if !is_nft_token_id(&withdrawal.token_id) {
+                self.internal_decrease_total(&withdrawal.token_id, transferred);
+            }
+            ContractEvent::WithdrawalCompleted {
+                nonce: withdrawal.nonce,
+                account_id: withdrawal.account_id.clone(),
+                token_id: withdrawal.token_id.clone(),
+                amount: U128(transferred),
+            }
+            .emit();
+        }
+
+        if refund > 0 {
+            // Restore the failed amount to the available balance
+            if is_nft_token_id(&withdrawal.token_id) {
+                self.internal_deposit_nft(&withdrawal.account_id, &withdrawal.token_id);
+            } else {
+                self.internal_deposit(&withdrawal.account_id, &withdrawal.token_id, refund);
+                Nep245Event::mint(&withdrawal.account_id, &withdrawal.token_id, refund).emit();
+            }
+            ContractEvent::WithdrawalReverted {
+                nonce: withdrawal.nonce,
+                account_id: withdrawal.account_id.clone(),
+                token_id: withdrawal.token_id.clone(),
+                amount: U128(refund),
+            }
+            .emit();
+        }
+
+        U128(refund)
+    }
+
+    /// Removes a pending withdrawal and its amount from the pending balance.
+    fn internal_settle_withdrawal(&mut self, nonce: u64) -> PendingWithdrawal {
+        let withdrawal = self
+            .pending_withdrawals
+            .remove(&nonce)
+            .unwrap_or_else(|| panic!("Pending withdrawal not found"));
+
+        let key = (withdrawal.account_id.clone(), withdrawal.token_id.clone());
+        let pending = self.pending_balances.get(&key).copied().unwrap_or(0);
+        if pending <= withdrawal.amount.0 {
+            self.pending_balances.remove(&key);
+        } else {
+            self.pending_balances
+                .insert(key, pending - withdrawal.amount.0);
+        }
+        self.internal_remove_commitment(&withdrawal.account_id);
+
+        withdrawal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::{testing_env, IntoStorageKey};
+
+    /// Contract where accounts 1 and 2 are registered and account 1 holds 100 USDC.
+    fn registered_contract() -> Contract {
+        testing_env!(VMContextBuilder::new()
+            .attached_deposit(NearToken::from_near(1))
+            .build());
+        let mut contract = Contract::new("intents.near".parse().unwrap(), None);
+        contract.storage_deposit(Some(accounts(1)), None);
+        contract.storage_deposit(Some(accounts(2)), None);
+        contract.internal_deposit(&accounts(1), "nep141:usdc.near", 100);
+        contract
+    }
+
+    fn set_caller(account_id: AccountId) {
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id(account_id)
+            .attached_deposit(NearToken::from_yoctonear(1))
+            .build());
+    }
+
+    #[test]
+    fn account_balances_do_not_collide() {
+        testing_env!(VMContextBuilder::new().build());
+        let mut contract = Contract::new("intents.near".parse().unwrap(), None);
+        let a: AccountId = "a.near".parse().unwrap();
+        let a_x: AccountId = "a.near.x".parse().unwrap();
+
+        // Neither account's prefix is a prefix of the other's, or of the top level collections
+        let a_key = account_balances_key(&a).into_storage_key();
+        let a_x_key = account_balances_key(&a_x).into_storage_key();
+        assert!(!a_x_key.starts_with(&a_key) && !a_key.starts_with(&a_x_key));
+        assert!(!a_key.starts_with(&StorageKey::Balances.into_storage_key()));
+
+        contract.internal_deposit(&a, "nep141:usdc.near", 10);
+        contract.internal_deposit(&a_x, "nep141:usdc.near", 20);
+        contract.internal_deposit(&a_x, "nep141:wrap.near", 30);
+
+        assert_eq!(
+            contract.get_tokens_for_account(a, &None, &None),
+            vec![("nep141:usdc.near".to_string(), U128(10))]
+        );
+        assert_eq!(
+            contract.get_tokens_for_account(a_x, &None, &None),
+            vec![
+                ("nep141:usdc.near".to_string(), U128(20)),
+                ("nep141:wrap.near".to_string(), U128(30))
+            ]
+        );
+    }
+
+    #[test]
+    fn storage_keys_keep_their_prefixes() {
+        // Deployed collections are stored under these prefixes, they must never change
+        for (key, prefix) in [
+            (StorageKey::Balances, 0),
+            (StorageKey::StateVersion, 4),
+            (StorageKey::FtAllowlist, 6),
+            (StorageKey::Nfts, 7),
+            (StorageKey::TokenRegistry, 9),
+            (StorageKey::TokenTotals, 10),
+            (StorageKey::RewardStakes, 14),
+            (StorageKey::AccountCommitments, 15),
+        ] {
+            assert_eq!(key.into_storage_key(), vec![prefix]);
+        }
+        assert_eq!(
+            account_balances_key(&"a.near".parse().unwrap()).into_storage_key()[0],
+            5
+        );
+    }
+
+    #[test]
+    fn withdraw_to_memo_carries_the_destination() {
+        assert_eq!(
+            withdraw_to_memo("0xabc", None),
+            "WITHDRAW_TO:0xabc".to_string()
+        );
+        assert_eq!(
+            withdraw_to_memo("0xabc", Some("order-1".to_string())),
+            "WITHDRAW_TO:0xabc:order-1".to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot unregister an account with vesting schedules")]
+    fn beneficiary_cannot_unregister() {
+        let mut contract = registered_contract();
+        set_caller(accounts(1));
+        contract.create_vesting(
+            accounts(2),
+            "nep141:usdc.near".parse().unwrap(),
+            U128(50),
+            U64(0),
+            U64(0),
+            U64(1_000),
+            false,
+        );
+
+        set_caller(accounts(2));
+        contract.storage_unregister(None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot unregister an account that still uses storage")]
+    fn funder_cannot_unregister() {
+        let mut contract = registered_contract();
+        set_caller(accounts(1));
+        contract.create_vesting(
+            accounts(2),
+            "nep141:usdc.near".parse().unwrap(),
+            U128(100),
+            U64(0),
+            U64(0),
+            U64(1_000),
+            false,
+        );
+
+        // The funder's balance is all vesting, only the schedule's storage is left
+        assert!(contract.balances.get(&accounts(1)).is_none());
+        contract.storage_unregister(None);
+    }
+
+    #[test]
+    fn sync_skips_accounts_without_storage_for_a_stake() {
+        let mut contract = registered_contract();
+        // A balance restored by a callback leaves its account registered without a deposit
+        contract.internal_deposit(&accounts(3), "nep141:usdc.near", 10);
+
+        set_caller(accounts(0));
+        contract.create_reward_pool(
+            "nep141:usdc.near".parse().unwrap(),
+            "nep141:usdc.near".parse().unwrap(),
+            U64(1_000),
+        );
+        contract.sync_reward_stakes(
+            "nep141:usdc.near".parse().unwrap(),
+            vec![accounts(1), accounts(3)],
+        );
+
+        assert!(contract
+            .reward_stakes
+            .contains_key(&(accounts(1), "nep141:usdc.near".to_string())));
+        assert!(!contract
+            .reward_stakes
+            .contains_key(&(accounts(3), "nep141:usdc.near".to_string())));
+    }
+
+    #[test]
+    #[should_panic(expected = "Forced unregistration is not supported")]
+    fn forced_unregistration_is_refused() {
+        let mut contract = registered_contract();
+        set_caller(accounts(2));
+        contract.storage_unregister(Some(true));
+    }
+
+    #[test]
+    fn withdrawal_in_flight_blocks_unregister() {
+        let mut contract = registered_contract();
+        set_caller(accounts(1));
+        contract.withdraw_token("nep141:usdc.near".parse().unwrap(), None, None);
+        assert!(contract.account_commitments.contains_key(&accounts(1)));
+
+        // The account can unregister once the withdrawal has settled
+        contract.internal_resolve_withdrawal(0, true);
+        assert!(!contract.account_commitments.contains_key(&accounts(1)));
+        assert!(contract.storage_unregister(None));
     }
 }
@@ -0,0 +1,156 @@
+use near_sdk::json_types::U64;
+use near_sdk::{env, near, serde_json, AccountId};
+
+use crate::timelock::MAX_LOCK_DURATION;
+use crate::token_id::TokenId;
+
+/// Version of the `msg` protocol accepted by `mt_on_transfer`.
+pub const TRANSFER_MSG_VERSION: u8 = 1;
+
+/// Message passed by the payer in `mt_transfer_call`, for example
+/// `{"action":"deposit","beneficiary":"bob.near","lock_duration":"86400000000000"}`.
+#[near(serializers = [json])]
+pub struct TransferMessage {
+    #[serde(default = "default_version")]
+    pub version: u8,
+    #[serde(flatten)]
+    pub action: TransferAction,
+}
+
+#[near(serializers = [json])]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum TransferAction {
+    /// Credits the transferred tokens to `beneficiary`, or to their previous owner if none is given.
+    /// With a `lock_duration` in nanoseconds, the tokens cannot leave the balance until it ends.
+    Deposit {
+        beneficiary: Option<AccountId>,
+        lock_duration: Option<U64>,
+    },
+    /// Adds the transferred tokens to the rewards of the pool staking `staked_token_id`, by
+    /// default the pool staking the transferred token itself. Only for reward funders.
+    FundRewards { staked_token_id: Option<TokenId> },
+}
+
+fn default_version() -> u8 {
+    TRANSFER_MSG_VERSION
+}
+
+impl TransferAction {
+    /// Keys a message with this action may contain besides `version` and `action`.
+    fn fields(&self) -> &'static [&'static str] {
+        match self {
+            Self::Deposit { .. } => &["beneficiary", "lock_duration"],
+            Self::FundRewards { .. } => &["staked_token_id"],
+        }
+    }
+
+    /// Whether a deposit locks tokens for an account other than `sender_id`. Such deposits are
+    /// refunded, as they would fill up another account's lots and storage.
+    pub fn locks_for_other(&self, sender_id: &AccountId) -> bool {
+        match self {
+            Self::Deposit {
+                beneficiary: Some(beneficiary),
+                lock_duration: Some(duration),
+            } => duration.0 > 0 && beneficiary != sender_id,
+            _ => false,
+        }
+    }
+}
+
+impl TransferMessage {
+    /// Parses a transfer message, an empty message being a plain deposit for the previous owner.
+    /// Returns `None` for malformed messages, unknown keys, unsupported versions and locks longer
+    /// than the maximum.
+    pub fn parse(msg: &str) -> Option<Self> {
+        if msg.is_empty() {
+            return Some(Self {
+                version: TRANSFER_MSG_VERSION,
+                action: TransferAction::Deposit {
+                    beneficiary: None,
+                    lock_duration: None,
+                },
+            });
+        }
+
+        // Serde ignores unknown keys of a flattened enum, so a misspelled key is caught here
+        let value = serde_json::from_str::<serde_json::Value>(msg).ok()?;
+        let keys: Vec<String> = value.as_object()?.keys().cloned().collect();
+
+        serde_json::from_value::<Self>(value)
+            .ok()
+            .filter(|message| {
+                keys.iter().all(|key| {
+                    key == "version"
+                        || key == "action"
+                        || message.action.fields().contains(&key.as_str())
+                })
+            })
+            .filter(|message| message.version == TRANSFER_MSG_VERSION)
+            .filter(|message| match &message.action {
+                TransferAction::Deposit { lock_duration, .. } => {
+                    lock_duration.is_none_or(|duration| duration.0 <= MAX_LOCK_DURATION)
+                }
+                TransferAction::FundRewards { .. } => true,
+            })
+    }
+
+    /// Block timestamp until which a deposit stays locked, `None` if it is not locked.
+    pub fn unlock_at(lock_duration: Option<U64>) -> Option<u64> {
+        lock_duration
+            .filter(|duration| duration.0 > 0)
+            .map(|duration| env::block_timestamp() + duration.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_deposit_messages() {
+        let message = TransferMessage::parse(r#"{"action":"deposit","beneficiary":"bob.near"}"#)
+            .expect("valid message");
+        assert!(matches!(
+            message.action,
+            TransferAction::Deposit { beneficiary: Some(beneficiary), lock_duration: None }
+                if beneficiary.as_str() == "bob.near"
+        ));
+    }
+
+    #[test]
+    fn only_locks_for_the_sender() {
+        let sender_id: AccountId = "alice.near".parse().unwrap();
+        for (msg, locks_for_other) in [
+            (r#"{"action":"deposit","lock_duration":"1"}"#, false),
+            (
+                r#"{"action":"deposit","beneficiary":"alice.near","lock_duration":"1"}"#,
+                false,
+            ),
+            (r#"{"action":"deposit","beneficiary":"bob.near"}"#, false),
+            (
+                r#"{"action":"deposit","beneficiary":"bob.near","lock_duration":"1"}"#,
+                true,
+            ),
+        ] {
+            let message = TransferMessage::parse(msg).expect("valid message");
+            assert_eq!(
+                message.action.locks_for_other(&sender_id),
+                locks_for_other,
+                "{}",
+                msg
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        for msg in [
+            r#"{"action":"deposit","benificiary":"bob.near"}"#,
+            r#"{"action":"deposit","staked_token_id":"nep141:usdc.near"}"#,
+            r#"{"action":"fund_rewards","beneficiary":"bob.near"}"#,
+            r#"["deposit"]"#,
+        ] {
+            assert!(TransferMessage::parse(msg).is_none(), "{}", msg);
+        }
+    }
+}
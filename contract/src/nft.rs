@@ -0,0 +1,199 @@
+use near_sdk::json_types::U128;
+use near_sdk::store::IterableSet;
+use near_sdk::{
+    assert_one_yocto, env, log, near, require, AccountId, NearToken, Promise, PromiseOrValue,
+};
+use near_sdk_contract_tools::nft::Nep171Receiver;
+
+use crate::events::ContractEvent;
+use crate::ext_mt::*;
+use crate::ext_nft::*;
+use crate::msg::{TransferAction, TransferMessage};
+use crate::token_id::TokenId;
+use crate::{Contract, ContractExt, StorageKey};
+
+/// Longest NFT token Id accepted, not counting its contract, which keeps the bytes an NFT uses
+/// within `NFT_ENTRY_STORAGE_BYTES`.
+pub const MAX_NFT_TOKEN_ID_LEN: usize = 128;
+
+/// Storage key of an account's NFT set.
+fn account_nfts_key(account_id: &AccountId) -> StorageKey {
+    StorageKey::AccountNfts {
+        account_hash: env::sha256_array(account_id.as_bytes()),
+    }
+}
+
+#[near]
+impl Nep171Receiver for Contract {
+    /// Locks an NFT for `previous_owner_id`, or the beneficiary in `msg`. Returns `true` to send
+    /// the NFT back.
+    fn nft_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_id: AccountId,
+        token_id: String,
+        msg: String,
+    ) -> PromiseOrValue<bool> {
+        if !self.deposits_allowed() {
+            log!("Deposits are paused, returning the NFT");
+            return PromiseOrValue::Value(true);
+        }
+
+        let Some(message) = TransferMessage::parse(&msg) else {
+            log!("Returning NFT sent with an invalid message");
+            return PromiseOrValue::Value(true);
+        };
+
+        let beneficiary = match message.action {
+            TransferAction::Deposit {
+                lock_duration: Some(_),
+                ..
+            } => {
+                log!("Returning NFT sent with a lock, NFTs cannot be locked");
+                return PromiseOrValue::Value(true);
+            }
+            TransferAction::Deposit { beneficiary, .. } => beneficiary,
+            TransferAction::FundRewards { .. } => {
+                log!("Returning NFT sent as rewards");
+                return PromiseOrValue::Value(true);
+            }
+        };
+        let account_id = beneficiary.unwrap_or(previous_owner_id);
+        let token_id = TokenId::Nft {
+            contract_id: env::predecessor_account_id(),
+            token_id,
+        };
+        if let Err(reason) = self.check_nft_deposit(&token_id) {
+            log!("Returning NFT {}, {}", token_id, reason);
+            return PromiseOrValue::Value(true);
+        }
+        let token_id = token_id.to_string();
+
+        if !self.internal_has_storage_for(&account_id, &token_id) {
+            log!(
+                "Returning NFT {}, {} has not registered enough storage",
+                token_id,
+                account_id
+            );
+            return PromiseOrValue::Value(true);
+        }
+
+        self.internal_deposit_nft(&account_id, &token_id);
+
+        ContractEvent::Deposit {
+            account_id,
+            sender_id,
+            token_id,
+            amount: U128(1),
+        }
+        .emit();
+
+        PromiseOrValue::Value(false)
+    }
+}
+
+#[near]
+impl Contract {
+    /// Withdraws a locked NFT to `receiver_id` (the caller by default). NFTs locked directly are
+    /// sent with `nft_transfer`, wrapped ones with `mt_transfer` on the intents contract. The NFT
+    /// is locked again if the transfer fails. Requires 1 yoctoNEAR, like `withdraw_token`.
+    #[payable]
+    pub fn withdraw_nft(&mut self, token_id: TokenId, receiver_id: Option<AccountId>) -> Promise {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        let receiver_id = receiver_id.unwrap_or_else(|| account_id.clone());
+        require!(token_id.is_nft(), "Not an NFT token Id");
+        self.assert_withdrawal_allowed(&account_id, &receiver_id, false);
+
+        let ledger_id = token_id.to_string();
+        self.internal_withdraw_nft(&account_id, &ledger_id);
+        let nonce =
+            self.internal_start_withdrawal(&account_id, receiver_id.as_str(), &ledger_id, 1);
+
+        let transfer = match token_id {
+            TokenId::Nft {
+                contract_id,
+                token_id,
+            } => nft_contract::ext(contract_id)
+                .with_attached_deposit(NearToken::from_yoctonear(1))
+                .with_static_gas(self.gas.nft_transfer)
+                .nft_transfer(receiver_id, token_id, None, None),
+            _ => mt_contract::ext(self.intents_contract_id.clone())
+                .with_attached_deposit(NearToken::from_yoctonear(1))
+                .with_static_gas(self.gas.mt_transfer)
+                .mt_transfer(receiver_id, ledger_id, U128(1)),
+        };
+
+        transfer.then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(self.gas.callback)
+                .withdraw_callback(nonce),
+        )
+    }
+
+    /// NFTs locked by an account, both the ones locked directly and the ones wrapped in intents.near.
+    pub fn get_nfts_for_account(
+        &self,
+        account: AccountId,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<String> {
+        let Some(nfts) = self.nfts.get(&account) else {
+            return Vec::new();
+        };
+        let from = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(nfts.len());
+
+        nfts.iter()
+            .skip(from as usize)
+            .take(limit as usize)
+            .cloned()
+            .collect()
+    }
+
+    pub fn account_has_nft(&self, account: AccountId, token_id: TokenId) -> bool {
+        self.nfts
+            .get(&account)
+            .is_some_and(|nfts| nfts.contains(&token_id.to_string()))
+    }
+}
+
+impl Contract {
+    /// Records an NFT as locked by an account, charging the storage it uses.
+    pub(crate) fn internal_deposit_nft(&mut self, account_id: &AccountId, token_id: &str) {
+        let initial_storage = env::storage_usage();
+
+        if self.nfts.get(account_id).is_none() {
+            self.nfts.insert(
+                account_id.clone(),
+                IterableSet::new(account_nfts_key(account_id)),
+            );
+        }
+
+        let nfts = self.nfts.get_mut(account_id).unwrap();
+        require!(nfts.insert(token_id.to_string()), "NFT is already locked");
+
+        // Write the entries now, so the storage they use can be measured
+        nfts.flush();
+        self.nfts.flush();
+        self.internal_settle_storage(account_id, initial_storage);
+    }
+
+    /// Removes an NFT from the ones locked by an account, crediting the storage it freed back.
+    pub(crate) fn internal_withdraw_nft(&mut self, account_id: &AccountId, token_id: &str) {
+        let initial_storage = env::storage_usage();
+
+        let nfts = self
+            .nfts
+            .get_mut(account_id)
+            .unwrap_or_else(|| panic!("No NFTs found for account"));
+        require!(nfts.remove(token_id), "NFT is not locked by the account");
+        nfts.flush();
+
+        if nfts.is_empty() {
+            self.nfts.remove(account_id);
+        }
+        self.nfts.flush();
+        self.internal_settle_storage(account_id, initial_storage);
+    }
+}
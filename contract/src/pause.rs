@@ -0,0 +1,68 @@
+use near_sdk::{log, near, require, AccountId};
+
+use crate::admin::Role;
+use crate::{Contract, ContractExt};
+
+/// Circuit breakers for incidents on the bridge or the intents contract.
+#[near(serializers = [borsh, json])]
+#[derive(Clone, Default)]
+pub struct PauseState {
+    /// Deposits are refunded in full.
+    pub deposits_paused: bool,
+    /// Withdrawals are refused.
+    pub withdrawals_paused: bool,
+    /// Deposits are refunded and only withdrawals back to the account's owner are allowed.
+    pub emergency: bool,
+}
+
+#[near]
+impl Contract {
+    /// Pauses or unpauses deposits and withdrawals, flags left out keep their value.
+    pub fn set_paused(&mut self, deposits: Option<bool>, withdrawals: Option<bool>) {
+        self.assert_owner_or_role(&Role::Pauser);
+        if let Some(deposits) = deposits {
+            self.pause.deposits_paused = deposits;
+        }
+        if let Some(withdrawals) = withdrawals {
+            self.pause.withdrawals_paused = withdrawals;
+        }
+        log!(
+            "Deposits paused: {}, withdrawals paused: {}",
+            self.pause.deposits_paused,
+            self.pause.withdrawals_paused
+        );
+    }
+
+    pub fn set_emergency_mode(&mut self, enabled: bool) {
+        self.assert_owner_or_role(&Role::Pauser);
+        self.pause.emergency = enabled;
+        log!("Emergency mode: {}", enabled);
+    }
+
+    pub fn get_pause_state(&self) -> PauseState {
+        self.pause.clone()
+    }
+}
+
+impl Contract {
+    pub(crate) fn deposits_allowed(&self) -> bool {
+        !self.pause.deposits_paused && !self.pause.emergency
+    }
+
+    /// Panics unless `account_id` may withdraw to `receiver_id`. In emergency mode tokens can
+    /// only go back to the account that owns them, and not through the bridge.
+    pub(crate) fn assert_withdrawal_allowed(
+        &self,
+        account_id: &AccountId,
+        receiver_id: &AccountId,
+        bridged: bool,
+    ) {
+        require!(!self.pause.withdrawals_paused, "Withdrawals are paused");
+        if self.pause.emergency {
+            require!(
+                !bridged && receiver_id == account_id,
+                "Only withdrawals to the owner are allowed in emergency mode"
+            );
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! NEP-245 receipt tokens for locked balances.
+//!
+//! Every fungible (account, token) balance of the ledger is exposed 1:1 as a receipt token with
+//! the same token Id. The methods read and move the ledger directly rather than going through the
+//! `near-sdk-contract-tools` multi-token component, so balances are only stored once. Receipts
+//! are minted on deposit, burned when a withdrawal starts and minted again if it fails. Wrapped
+//! NFTs have no receipts.
+
+use near_sdk::json_types::U128;
+use near_sdk::{assert_one_yocto, env, near, require, AccountId, PromiseError, PromiseOrValue};
+
+use crate::ext_mt::*;
+use crate::token_id::TokenId;
+use crate::transfer::{RESOLVE_TRANSFER_GAS, TRANSFER_CALL_GAS};
+use crate::{Contract, ContractExt};
+
+/// Receipt token metadata returned by `mt_token`.
+#[near(serializers = [json])]
+pub struct Token {
+    pub token_id: String,
+    pub owner_id: Option<AccountId>,
+}
+
+#[near]
+impl Contract {
+    /// Receipt balance of an account, lots still locked by a deposit lock included.
+    /// `get_locked_balance` splits it into what is locked and what can be withdrawn.
+    pub fn mt_balance_of(&self, account_id: AccountId, token_id: TokenId) -> U128 {
+        U128(
+            self.internal_available_balance(&account_id, &token_id.to_string())
+                .unwrap_or(0),
+        )
+    }
+
+    pub fn mt_batch_balance_of(&self, account_id: AccountId, token_ids: Vec<TokenId>) -> Vec<U128> {
+        token_ids
+            .into_iter()
+            .map(|token_id| self.mt_balance_of(account_id.clone(), token_id))
+            .collect()
+    }
+
+    /// Receipts in circulation for a token. Withdrawals in flight are still counted until they
+    /// settle, even though their receipts are already burned.
+    pub fn mt_supply(&self, token_id: TokenId) -> Option<U128> {
+        if token_id.is_nft() {
+            return None;
+        }
+        let token_id = token_id.to_string();
+        let total = self.internal_token_total(&token_id);
+        (total > 0 || self.token_registry.get(&token_id).is_some()).then_some(U128(total))
+    }
+
+    pub fn mt_batch_supply(&self, token_ids: Vec<TokenId>) -> Vec<Option<U128>> {
+        token_ids
+            .into_iter()
+            .map(|token_id| self.mt_supply(token_id))
+            .collect()
+    }
+
+    /// Receipt tokens known to the contract, `None` for tokens it has never held or listed.
+    pub fn mt_token(&self, token_ids: Vec<TokenId>) -> Vec<Option<Token>> {
+        token_ids
+            .into_iter()
+            .map(|token_id| {
+                self.mt_supply(token_id.clone()).map(|_| Token {
+                    token_id: token_id.to_string(),
+                    owner_id: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Transfers receipts, and the locked balance they represent, to `receiver_id`. Requires
+    /// exactly 1 yoctoNEAR attached. Approvals are not supported.
+    #[payable]
+    pub fn mt_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        approval: Option<(AccountId, u64)>,
+        memo: Option<String>,
+    ) {
+        assert_one_yocto();
+        require!(approval.is_none(), "Approvals are not supported");
+        let sender_id = env::predecessor_account_id();
+        self.internal_transfer(&sender_id, &receiver_id, &token_id, amount.0, memo);
+    }
+
+    #[payable]
+    pub fn mt_batch_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        approvals: Option<Vec<Option<(AccountId, u64)>>>,
+        memo: Option<String>,
+    ) {
+        assert_one_yocto();
+        self.internal_batch_transfer(&receiver_id, &token_ids, &amounts, approvals, memo);
+    }
+
+    /// Transfers receipts to the `receiver_id` contract and calls its `mt_on_transfer` with
+    /// `msg`. The amount the receiver returns is transferred back to the caller.
+    #[payable]
+    pub fn mt_transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        approval: Option<(AccountId, u64)>,
+        memo: Option<String>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        self.mt_batch_transfer_call(
+            receiver_id,
+            vec![token_id],
+            vec![amount],
+            approval.map(|approval| vec![Some(approval)]),
+            memo,
+            msg,
+        )
+    }
+
+    #[payable]
+    pub fn mt_batch_transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        approvals: Option<Vec<Option<(AccountId, u64)>>>,
+        memo: Option<String>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        assert_one_yocto();
+        require!(
+            env::prepaid_gas() >= TRANSFER_CALL_GAS.saturating_add(RESOLVE_TRANSFER_GAS),
+            "Not enough gas attached"
+        );
+        let sender_id =
+            self.internal_batch_transfer(&receiver_id, &token_ids, &amounts, approvals, memo);
+        let token_ids: Vec<String> = token_ids.iter().map(ToString::to_string).collect();
+
+        mt_receiver::ext(receiver_id.clone())
+            .with_static_gas(TRANSFER_CALL_GAS)
+            .mt_on_transfer(
+                sender_id.clone(),
+                vec![sender_id.clone(); token_ids.len()],
+                token_ids.clone(),
+                amounts.clone(),
+                msg,
+            )
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(RESOLVE_TRANSFER_GAS)
+                    .with_unused_gas_weight(0)
+                    .mt_resolve_transfer(
+                        vec![sender_id; token_ids.len()],
+                        receiver_id,
+                        token_ids,
+                        amounts,
+                    ),
+            )
+            .into()
+    }
+
+    /// Transfers the refunds the receiver of an `mt_transfer_call` or `transfer_call` asked for back to their
+    /// previous owners, as far as the receiver still holds them. Returns the amounts the
+    /// receiver kept.
+    #[private]
+    pub fn mt_resolve_transfer(
+        &mut self,
+        #[callback_result] call_result: Result<Vec<U128>, PromiseError>,
+        previous_owner_ids: Vec<AccountId>,
+        receiver_id: AccountId,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+    ) -> Vec<U128> {
+        // Refund everything if the receiver failed or answered with the wrong number of amounts
+        let requested = call_result
+            .ok()
+            .filter(|refunds| refunds.len() == amounts.len())
+            .unwrap_or_else(|| amounts.clone());
+
+        previous_owner_ids
+            .iter()
+            .zip(token_ids.iter())
+            .zip(amounts.iter().zip(requested))
+            .map(|((previous_owner_id, token_id), (amount, requested))| {
+                let refund = self.internal_resolve_transfer(
+                    previous_owner_id,
+                    &receiver_id,
+                    token_id,
+                    amount.0,
+                    requested.0,
+                );
+                U128(amount.0 - refund)
+            })
+            .collect()
+    }
+}
+
+impl Contract {
+    /// Transfers a batch of receipts from the caller and returns the caller's account.
+    fn internal_batch_transfer(
+        &mut self,
+        receiver_id: &AccountId,
+        token_ids: &[TokenId],
+        amounts: &[U128],
+        approvals: Option<Vec<Option<(AccountId, u64)>>>,
+        memo: Option<String>,
+    ) -> AccountId {
+        require!(!token_ids.is_empty(), "No tokens to transfer");
+        require!(token_ids.len() == amounts.len(), "Invalid input length");
+        require!(
+            approvals.is_none_or(|approvals| approvals.iter().all(Option::is_none)),
+            "Approvals are not supported"
+        );
+
+        let sender_id = env::predecessor_account_id();
+        for (token_id, amount) in token_ids.iter().zip(amounts) {
+            self.internal_transfer(&sender_id, receiver_id, token_id, amount.0, memo.clone());
+        }
+        sender_id
+    }
+}
@@ -0,0 +1,219 @@
+use near_sdk::json_types::U128;
+use near_sdk::{log, near, require, AccountId};
+
+use crate::admin::Role;
+use crate::nft::MAX_NFT_TOKEN_ID_LEN;
+use crate::token_id::TokenId;
+use crate::{Contract, ContractExt};
+
+/// Configuration of a token the contract accepts.
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct TokenConfig {
+    /// Deposits of disabled tokens are refunded, their balances can still be withdrawn.
+    pub enabled: bool,
+    /// Smallest amount accepted in a single deposit.
+    pub min_deposit: U128,
+    /// Smallest amount of a single withdrawal, unless it empties the balance.
+    pub min_withdrawal: U128,
+    /// Display symbol, cached for frontends.
+    pub symbol: String,
+    /// Display decimals, cached for frontends.
+    pub decimals: u8,
+    /// Chain the token is bridged from, for example `eth` or `near`.
+    pub origin_chain: String,
+    /// Most of the token all accounts together can hold, no limit if not set.
+    pub global_cap: Option<U128>,
+    /// Most of the token a single account can hold, no limit if not set.
+    pub account_cap: Option<U128>,
+}
+
+/// Registry key of an NFT collection, `nep171:<contract>` for NFTs wrapped in intents.near and
+/// `nft:<contract>` for NFTs locked directly.
+fn nft_collection_id(contract_id: &AccountId, wrapped: bool) -> String {
+    let standard = if wrapped { "nep171" } else { "nft" };
+    format!("{}:{}", standard, contract_id)
+}
+
+#[near]
+impl Contract {
+    /// Adds a token to the registry or replaces its configuration.
+    pub fn register_token(&mut self, token_id: TokenId, config: TokenConfig) {
+        self.assert_owner_or_role(&Role::Admin);
+        require!(
+            !token_id.is_nft(),
+            "NFT collections are registered with register_nft_collection"
+        );
+        log!("Token {} registered", token_id);
+        self.token_registry.insert(token_id.to_string(), config);
+    }
+
+    /// Lists an NFT collection so its NFTs can be locked, the ones wrapped in intents.near when
+    /// `wrapped` is set and the ones locked directly otherwise. NFTs of collections that are not
+    /// listed or are disabled are returned, only `enabled` applies to them.
+    pub fn register_nft_collection(
+        &mut self,
+        contract_id: AccountId,
+        wrapped: bool,
+        config: TokenConfig,
+    ) {
+        self.assert_owner_or_role(&Role::Admin);
+        let collection_id = nft_collection_id(&contract_id, wrapped);
+        log!("NFT collection {} registered", collection_id);
+        self.token_registry.insert(collection_id, config);
+    }
+
+    /// Removes an NFT collection from the registry, returning its future deposits. Locked NFTs
+    /// can still be withdrawn.
+    pub fn unregister_nft_collection(&mut self, contract_id: AccountId, wrapped: bool) -> bool {
+        self.assert_owner_or_role(&Role::Admin);
+        self.token_registry
+            .remove(&nft_collection_id(&contract_id, wrapped))
+            .is_some()
+    }
+
+    /// Removes a token from the registry, refunding its future deposits. Locked balances can
+    /// still be withdrawn.
+    pub fn unregister_token(&mut self, token_id: TokenId) -> bool {
+        self.assert_owner_or_role(&Role::Admin);
+        self.token_registry.remove(&token_id.to_string()).is_some()
+    }
+
+    pub fn set_token_enabled(&mut self, token_id: TokenId, enabled: bool) {
+        self.assert_owner_or_role(&Role::Admin);
+        let config = self
+            .token_registry
+            .get_mut(&token_id.to_string())
+            .unwrap_or_else(|| panic!("Token is not registered"));
+        config.enabled = enabled;
+        log!("Token {} enabled: {}", token_id, enabled);
+    }
+
+    /// Sets the deposit caps of a token, `None` removing a cap. Lowering a cap below what is
+    /// already held only limits new deposits.
+    pub fn set_token_caps(
+        &mut self,
+        token_id: TokenId,
+        global_cap: Option<U128>,
+        account_cap: Option<U128>,
+    ) {
+        self.assert_owner_or_role(&Role::Admin);
+        let config = self
+            .token_registry
+            .get_mut(&token_id.to_string())
+            .unwrap_or_else(|| panic!("Token is not registered"));
+        config.global_cap = global_cap;
+        config.account_cap = account_cap;
+    }
+
+    pub fn get_token_config(&self, token_id: TokenId) -> Option<TokenConfig> {
+        self.token_registry.get(&token_id.to_string()).cloned()
+    }
+
+    /// Tokens in the registry, enabled or not, with their configuration.
+    pub fn get_supported_tokens(
+        &self,
+        from_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<(String, TokenConfig)> {
+        let from = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(self.token_registry.len());
+
+        self.token_registry
+            .iter()
+            .skip(from as usize)
+            .take(limit as usize)
+            .map(|(token_id, config)| (token_id.clone(), config.clone()))
+            .collect()
+    }
+}
+
+impl Contract {
+    /// Checks a deposit against the token's registry entry, returning why it is refused.
+    pub(crate) fn check_deposit(&self, token_id: &str, amount: u128) -> Result<(), &'static str> {
+        let config = self
+            .token_registry
+            .get(token_id)
+            .ok_or("the token is not supported")?;
+        if !config.enabled {
+            return Err("the token is disabled");
+        }
+        if amount < config.min_deposit.0 {
+            return Err("the amount is below the minimum deposit");
+        }
+        Ok(())
+    }
+
+    /// Checks an NFT deposit against its collection's registry entry, returning why it is refused.
+    pub(crate) fn check_nft_deposit(&self, token_id: &TokenId) -> Result<(), &'static str> {
+        let (contract_id, id, wrapped) = match token_id {
+            TokenId::Nep171 {
+                contract_id,
+                token_id,
+            } => (contract_id, token_id, true),
+            TokenId::Nft {
+                contract_id,
+                token_id,
+            } => (contract_id, token_id, false),
+            _ => return Err("the token is not an NFT"),
+        };
+        if id.len() > MAX_NFT_TOKEN_ID_LEN {
+            return Err("the token Id is too long");
+        }
+        let config = self
+            .token_registry
+            .get(&nft_collection_id(contract_id, wrapped))
+            .ok_or("the collection is not supported")?;
+        if !config.enabled {
+            return Err("the collection is disabled");
+        }
+        Ok(())
+    }
+
+    /// Part of a deposit that fits under the token's global and per-account caps, zero if that
+    /// part is below the token's minimum deposit. Pending withdrawals still count towards the
+    /// caps until they settle.
+    pub(crate) fn internal_capped_amount(
+        &self,
+        account_id: &AccountId,
+        token_id: &str,
+        amount: u128,
+    ) -> u128 {
+        let Some(config) = self.token_registry.get(token_id) else {
+            return amount;
+        };
+
+        let mut accepted = amount;
+        if let Some(global_cap) = config.global_cap {
+            let total = self.internal_token_total(token_id);
+            accepted = accepted.min(global_cap.0.saturating_sub(total));
+        }
+        if let Some(account_cap) = config.account_cap {
+            let held = self
+                .internal_available_balance(account_id, token_id)
+                .unwrap_or(0)
+                + self
+                    .pending_balances
+                    .get(&(account_id.clone(), token_id.to_string()))
+                    .copied()
+                    .unwrap_or(0);
+            accepted = accepted.min(account_cap.0.saturating_sub(held));
+        }
+
+        // A capped deposit must still meet the minimum, or the whole deposit is refunded
+        if accepted < config.min_deposit.0 {
+            return 0;
+        }
+        accepted
+    }
+
+    /// Panics if a withdrawal is below the token's minimum without emptying the balance.
+    pub(crate) fn assert_min_withdrawal(&self, token_id: &str, amount: u128, balance: u128) {
+        if let Some(config) = self.token_registry.get(token_id) {
+            require!(
+                amount >= config.min_withdrawal.0 || amount == balance,
+                "The amount is below the minimum withdrawal"
+            );
+        }
+    }
+}
@@ -0,0 +1,463 @@
+use near_sdk::json_types::{U128, U64};
+use near_sdk::{env, log, near, require, AccountId, NearToken, Promise};
+
+use crate::admin::Role;
+use crate::events::{ContractEvent, Nep245Event};
+use crate::ext_mt::*;
+use crate::storage::STAKE_STORAGE_BYTES;
+use crate::token_id::TokenId;
+use crate::{Contract, ContractExt};
+
+/// Scale of `reward_per_share`.
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// Rewards paid to the holders of a staked token in proportion to their balance. Each funding
+/// is paid out linearly over `duration`, together with what was left of the previous one.
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct RewardPool {
+    pub reward_token_id: String,
+    /// Time over which each funding is paid out, in nanoseconds.
+    pub duration: U64,
+    /// Sum of the balances earning rewards.
+    pub total_staked: U128,
+    /// Rewards paid per staked token since the pool was created, scaled by `REWARD_PRECISION`.
+    pub reward_per_share: U128,
+    /// Amount paid out over the current period and how much of it is already paid.
+    pub period_amount: U128,
+    pub period_paid: U128,
+    pub period_start: U64,
+    pub period_end: U64,
+    pub last_update: U64,
+}
+
+/// Balance of a staked token an account earns rewards on, and the rewards it earned.
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct RewardStake {
+    pub amount: U128,
+    /// Value of the pool's `reward_per_share` when the rewards were last settled.
+    pub reward_per_share_paid: U128,
+    pub unclaimed: U128,
+}
+
+impl RewardPool {
+    /// Pays out the part of the current period that elapsed since the last update. Nothing is
+    /// paid while no tokens are staked, the rest of the period is pushed back instead. Amounts
+    /// too small to add to `reward_per_share` stay due until a later update.
+    pub fn update(&mut self, now: u64) {
+        if now <= self.last_update.0 {
+            return;
+        }
+        let elapsed = now - self.last_update.0;
+        let active = self.last_update.0 < self.period_end.0;
+        self.last_update = U64(now);
+
+        if self.total_staked.0 == 0 {
+            if active {
+                self.period_start = U64(self.period_start.0 + elapsed);
+                self.period_end = U64(self.period_end.0 + elapsed);
+            }
+            return;
+        }
+
+        let scheduled = if now >= self.period_end.0 {
+            self.period_amount.0
+        } else {
+            mul_div(
+                self.period_amount.0,
+                (now - self.period_start.0) as u128,
+                (self.period_end.0 - self.period_start.0) as u128,
+            )
+        };
+        let due = scheduled - self.period_paid.0;
+        let per_share = mul_div(due, REWARD_PRECISION, self.total_staked.0);
+        if per_share > 0 {
+            self.reward_per_share = U128(
+                self.reward_per_share
+                    .0
+                    .checked_add(per_share)
+                    .unwrap_or_else(|| panic!("Reward overflow")),
+            );
+            // Only what the stakes can claim counts as paid, the rounding loss is paid later
+            let paid = mul_div(per_share, self.total_staked.0, REWARD_PRECISION);
+            self.period_paid = U128(self.period_paid.0 + paid);
+        }
+    }
+
+    /// Starts a new period paying out `amount` and what is left of the current one.
+    pub fn fund(&mut self, amount: u128, now: u64) {
+        self.update(now);
+        let left = self.period_amount.0 - self.period_paid.0;
+        self.period_amount = U128(
+            left.checked_add(amount)
+                .unwrap_or_else(|| panic!("Reward overflow")),
+        );
+        self.period_paid = U128(0);
+        self.period_start = U64(now);
+        self.period_end = U64(now + self.duration.0);
+    }
+
+    /// Rewards earned by a stake since it was last settled.
+    pub fn earned(&self, stake: &RewardStake) -> u128 {
+        mul_div(
+            stake.amount.0,
+            self.reward_per_share.0 - stake.reward_per_share_paid.0,
+            REWARD_PRECISION,
+        )
+    }
+}
+
+#[near]
+impl Contract {
+    /// Creates a pool paying rewards in `reward_token_id` to the holders of `staked_token_id`.
+    /// Accounts that already hold the staked token only earn once their stake is recorded, so
+    /// record them with `sync_reward_stakes` before funding the pool.
+    pub fn create_reward_pool(
+        &mut self,
+        staked_token_id: TokenId,
+        reward_token_id: TokenId,
+        duration: U64,
+    ) {
+        self.assert_owner_or_role(&Role::Admin);
+        require!(!staked_token_id.is_nft(), "NFTs cannot be staked");
+        require!(
+            reward_token_id.is_intents() && !reward_token_id.is_nft(),
+            "Rewards must be paid in a fungible intents token"
+        );
+        require!(duration.0 > 0, "The reward duration must be positive");
+        let staked_token_id = staked_token_id.to_string();
+        require!(
+            !self.reward_pools.contains_key(&staked_token_id),
+            "Reward pool already exists"
+        );
+
+        let now = env::block_timestamp();
+        log!("Reward pool created for {}", staked_token_id);
+        self.reward_pools.insert(
+            staked_token_id,
+            RewardPool {
+                reward_token_id: reward_token_id.to_string(),
+                duration,
+                total_staked: U128(0),
+                reward_per_share: U128(0),
+                period_amount: U128(0),
+                period_paid: U128(0),
+                period_start: U64(now),
+                period_end: U64(now),
+                last_update: U64(now),
+            },
+        );
+    }
+
+    /// Records the balances `account_ids` hold of a staked token as their stakes in its pool,
+    /// settling what they earned so far. Accounts can sync their own stake, syncing others
+    /// takes the admin role. Accounts that cannot pay for a new stake's storage are skipped.
+    pub fn sync_reward_stakes(&mut self, staked_token_id: TokenId, account_ids: Vec<AccountId>) {
+        let caller_id = env::predecessor_account_id();
+        require!(
+            account_ids
+                .iter()
+                .all(|account_id| *account_id == caller_id)
+                || self.is_owner_or_role(&caller_id, &Role::Admin),
+            "Unauthorized"
+        );
+        let staked_token_id = staked_token_id.to_string();
+        require!(
+            self.reward_pools.contains_key(&staked_token_id),
+            "Reward pool not found"
+        );
+
+        for account_id in account_ids {
+            let key = (account_id.clone(), staked_token_id.clone());
+            if !self.reward_stakes.contains_key(&key)
+                && !self.internal_has_storage_for_bytes(
+                    &account_id,
+                    &staked_token_id,
+                    STAKE_STORAGE_BYTES,
+                )
+            {
+                log!(
+                    "Skipping {}, it has not registered enough storage for a stake",
+                    account_id
+                );
+                continue;
+            }
+            self.internal_sync_stake(&account_id, &staked_token_id);
+        }
+    }
+
+    pub fn get_reward_pool(&self, staked_token_id: TokenId) -> Option<RewardPool> {
+        self.reward_pools.get(&staked_token_id.to_string()).cloned()
+    }
+
+    /// Rewards an account can claim from the pool of a staked token.
+    pub fn get_unclaimed_rewards(&self, account_id: AccountId, staked_token_id: TokenId) -> U128 {
+        let staked_token_id = staked_token_id.to_string();
+        let Some(mut pool) = self.reward_pools.get(&staked_token_id).cloned() else {
+            return U128(0);
+        };
+        let Some(stake) = self.reward_stakes.get(&(account_id, staked_token_id)) else {
+            return U128(0);
+        };
+
+        pool.update(env::block_timestamp());
+        U128(stake.unclaimed.0 + pool.earned(stake))
+    }
+
+    /// Withdraws the caller's rewards from the pool of a staked token with `mt_transfer`, like
+    /// `withdraw_token`. A failed transfer is restored to the caller's balance.
+    pub fn claim_rewards(&mut self, staked_token_id: TokenId) -> Promise {
+        let account_id = env::predecessor_account_id();
+        self.assert_withdrawal_allowed(&account_id, &account_id, false);
+        let staked_token_id = staked_token_id.to_string();
+        let reward_token_id = self
+            .reward_pools
+            .get(&staked_token_id)
+            .map(|pool| pool.reward_token_id.clone())
+            .unwrap_or_else(|| panic!("Reward pool not found"));
+
+        self.internal_sync_stake(&account_id, &staked_token_id);
+        let key = (account_id.clone(), staked_token_id.clone());
+        let Some(stake) = self.reward_stakes.get_mut(&key) else {
+            panic!("No rewards to claim");
+        };
+        let amount = stake.unclaimed.0;
+        require!(amount > 0, "No rewards to claim");
+        stake.unclaimed = U128(0);
+        if stake.amount.0 == 0 {
+            let initial_storage = env::storage_usage();
+            self.reward_stakes.remove(&key);
+            self.reward_stakes.flush();
+            self.internal_settle_storage(&account_id, initial_storage);
+        }
+
+        ContractEvent::RewardsClaimed {
+            account_id: account_id.clone(),
+            staked_token_id,
+            reward_token_id: reward_token_id.clone(),
+            amount: U128(amount),
+        }
+        .emit();
+        // The rewards pass through the account's receipts on their way out
+        Nep245Event::mint(&account_id, &reward_token_id, amount).emit();
+        let nonce = self.internal_start_withdrawal(
+            &account_id,
+            account_id.as_str(),
+            &reward_token_id,
+            amount,
+        );
+
+        mt_contract::ext(self.intents_contract_id.clone())
+            .with_attached_deposit(NearToken::from_yoctonear(1))
+            .with_static_gas(self.gas.mt_transfer)
+            .mt_transfer(account_id, reward_token_id, U128(amount))
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(self.gas.callback)
+                    .withdraw_callback(nonce),
+            )
+    }
+}
+
+impl Contract {
+    /// Adds rewards sent with a `fund_rewards` message to the pools of their staked tokens, by
+    /// default the pool staking the reward token itself. Returns the amounts to refund.
+    pub(crate) fn internal_fund_rewards(
+        &mut self,
+        funder_id: &AccountId,
+        token_ids: &[String],
+        amounts: &[U128],
+        staked_token_id: Option<TokenId>,
+    ) -> Vec<U128> {
+        if !self.is_owner_or_role(funder_id, &Role::Admin)
+            && !self.is_owner_or_role(funder_id, &Role::RewardFunder)
+        {
+            log!(
+                "Refunding rewards from {}, only reward funders can fund rewards",
+                funder_id
+            );
+            return amounts.to_vec();
+        }
+
+        let now = env::block_timestamp();
+        let mut refunds = Vec::with_capacity(token_ids.len());
+        for (token_id, amount) in token_ids.iter().zip(amounts) {
+            let staked_token_id = staked_token_id
+                .as_ref()
+                .map_or_else(|| token_id.clone(), ToString::to_string);
+            let Some(pool) = self.reward_pools.get_mut(&staked_token_id) else {
+                log!(
+                    "Refunding {} of token {}, there is no reward pool for {}",
+                    amount.0,
+                    token_id,
+                    staked_token_id
+                );
+                refunds.push(*amount);
+                continue;
+            };
+            if pool.reward_token_id != *token_id {
+                log!(
+                    "Refunding {} of token {}, the pool pays rewards in {}",
+                    amount.0,
+                    token_id,
+                    pool.reward_token_id
+                );
+                refunds.push(*amount);
+                continue;
+            }
+
+            pool.fund(amount.0, now);
+            self.internal_increase_total(token_id, amount.0);
+            ContractEvent::RewardsFunded {
+                funder_id: funder_id.clone(),
+                staked_token_id,
+                reward_token_id: token_id.clone(),
+                amount: *amount,
+            }
+            .emit();
+            refunds.push(U128(0));
+        }
+
+        refunds
+    }
+
+    /// Settles the rewards an account earned on a staked token and records its current balance
+    /// as its stake. Called whenever the balance changes.
+    pub(crate) fn internal_sync_stake(&mut self, account_id: &AccountId, token_id: &str) {
+        if !self.reward_pools.contains_key(token_id) {
+            return;
+        }
+        let balance = self
+            .internal_available_balance(account_id, token_id)
+            .unwrap_or(0);
+        let key = (account_id.clone(), token_id.to_string());
+        let pool = self.reward_pools.get_mut(token_id).unwrap();
+        pool.update(env::block_timestamp());
+
+        let (previous, unclaimed) = match self.reward_stakes.get(&key) {
+            Some(stake) => (stake.amount.0, stake.unclaimed.0 + pool.earned(stake)),
+            None => (0, 0),
+        };
+        pool.total_staked = U128(pool.total_staked.0 - previous + balance);
+        let stake = RewardStake {
+            amount: U128(balance),
+            reward_per_share_paid: pool.reward_per_share,
+            unclaimed: U128(unclaimed),
+        };
+
+        let initial_storage = env::storage_usage();
+        if balance == 0 && unclaimed == 0 {
+            self.reward_stakes.remove(&key);
+        } else {
+            self.reward_stakes.insert(key, stake);
+        }
+        self.reward_stakes.flush();
+        self.internal_settle_storage(account_id, initial_storage);
+    }
+}
+
+/// `a * b / c` rounded down, with the product computed on 256 bits so it cannot overflow.
+pub(crate) fn mul_div(a: u128, b: u128, c: u128) -> u128 {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let mid = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let lo = (lo_lo & MASK) | (mid << 64);
+    let hi = a_hi * b_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+    require!(hi < c, "Reward overflow");
+
+    // Long division of the 256 bit product, the remainder always stays below `c`
+    let mut remainder = hi;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((lo >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= c {
+            remainder = remainder.wrapping_sub(c);
+            quotient |= 1;
+        }
+    }
+    quotient
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_div_handles_wide_products() {
+        assert_eq!(mul_div(10, 20, 7), 28);
+        assert_eq!(mul_div(u128::MAX, u128::MAX, u128::MAX), u128::MAX);
+        assert_eq!(mul_div(u128::MAX, 3, 4), u128::MAX / 4 * 3 + 2);
+        assert_eq!(
+            mul_div(10u128.pow(30), REWARD_PRECISION, 10u128.pow(24)),
+            10u128.pow(24)
+        );
+    }
+
+    #[test]
+    fn rewards_are_paid_out_over_the_period() {
+        let mut pool = RewardPool {
+            reward_token_id: "nep141:usdc.near".to_string(),
+            duration: U64(100),
+            total_staked: U128(0),
+            reward_per_share: U128(0),
+            period_amount: U128(0),
+            period_paid: U128(0),
+            period_start: U64(0),
+            period_end: U64(0),
+            last_update: U64(0),
+        };
+        pool.fund(1_000, 0);
+
+        // Nothing is paid while no tokens are staked
+        pool.update(50);
+        assert_eq!(pool.period_end.0, 150);
+
+        pool.total_staked = U128(10);
+        let stake = RewardStake {
+            amount: U128(10),
+            reward_per_share_paid: U128(0),
+            unclaimed: U128(0),
+        };
+        pool.update(100);
+        assert_eq!(pool.earned(&stake), 500);
+        pool.update(200);
+        assert_eq!(pool.earned(&stake), 1_000);
+    }
+
+    #[test]
+    fn frequent_updates_lose_no_rewards() {
+        // A billion tokens with 18 decimals staked, 10M USDC paid over 1000 updates, each
+        // update paying 3.33 per share before rounding
+        let mut pool = RewardPool {
+            reward_token_id: "nep141:usdc.near".to_string(),
+            duration: U64(1_000),
+            total_staked: U128(3 * 10u128.pow(27)),
+            reward_per_share: U128(0),
+            period_amount: U128(0),
+            period_paid: U128(0),
+            period_start: U64(0),
+            period_end: U64(0),
+            last_update: U64(0),
+        };
+        pool.fund(10u128.pow(13), 0);
+        for now in 1..=1_000 {
+            pool.update(now);
+        }
+
+        // Everything counted as paid can be claimed, and only less than one share is left due
+        let stake = RewardStake {
+            amount: pool.total_staked,
+            reward_per_share_paid: U128(0),
+            unclaimed: U128(0),
+        };
+        assert_eq!(pool.earned(&stake), pool.period_paid.0);
+        assert!(pool.period_amount.0 - pool.period_paid.0 < 3 * 10u128.pow(9));
+    }
+}
@@ -0,0 +1,291 @@
+use near_sdk::{assert_one_yocto, env, log, near, require, AccountId, NearToken, Promise};
+
+use crate::token_id::is_nft_token_id;
+use crate::{Contract, ContractExt};
+
+// The bytes records use are measured with `env::storage_usage()` when they are written. The
+// estimates below only check an account can pay for a record before it is written.
+
+/// Bytes used by an account's storage registration record.
+pub const STORAGE_REGISTRATION_BYTES: u64 = 125;
+/// Upper estimate of the bytes used by an account's balances map.
+pub const ACCOUNT_MAP_STORAGE_BYTES: u64 = 250;
+/// Upper estimate of the bytes used by each token entry in an account's balances map.
+pub const TOKEN_ENTRY_STORAGE_BYTES: u64 = 400;
+/// Upper estimate of the bytes used by an NFT entry, with a token Id of the longest length
+/// accepted.
+pub const NFT_ENTRY_STORAGE_BYTES: u64 = 700;
+/// Upper estimate of the bytes used by the lots entry of an account and token.
+pub const LOCK_ENTRY_STORAGE_BYTES: u64 = 400;
+/// Upper estimate of the bytes used by each lot in a lots entry.
+pub const LOT_STORAGE_BYTES: u64 = 24;
+/// Upper estimate of the bytes used by a vesting schedule.
+pub const VESTING_STORAGE_BYTES: u64 = 500;
+/// Upper estimate of the bytes used by an account's stake in a reward pool.
+pub const STAKE_STORAGE_BYTES: u64 = 250;
+
+/// Storage paid for by an account and how many bytes of it are in use.
+#[near(serializers = [borsh])]
+pub struct AccountStorage {
+    pub deposit: NearToken,
+    pub used_bytes: u64,
+}
+
+#[near(serializers = [json])]
+pub struct StorageBalance {
+    pub total: NearToken,
+    pub available: NearToken,
+}
+
+#[near(serializers = [json])]
+pub struct StorageBalanceBounds {
+    pub min: NearToken,
+    pub max: Option<NearToken>,
+}
+
+#[near]
+impl Contract {
+    #[payable]
+    pub fn storage_deposit(
+        &mut self,
+        account_id: Option<AccountId>,
+        registration_only: Option<bool>,
+    ) -> StorageBalance {
+        let amount = env::attached_deposit();
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let registration_only = registration_only.unwrap_or(false);
+        let min_balance = self.storage_balance_bounds().min;
+
+        let refund = if let Some(storage) = self.storage_deposits.get_mut(&account_id) {
+            if registration_only {
+                log!("The account is already registered, refunding the deposit");
+                amount
+            } else {
+                storage.deposit = storage.deposit.saturating_add(amount);
+                NearToken::from_yoctonear(0)
+            }
+        } else {
+            require!(
+                amount >= min_balance,
+                "The attached deposit is less than the minimum storage balance"
+            );
+            let deposit = if registration_only {
+                min_balance
+            } else {
+                amount
+            };
+            self.storage_deposits.insert(
+                account_id.clone(),
+                AccountStorage {
+                    deposit,
+                    used_bytes: STORAGE_REGISTRATION_BYTES,
+                },
+            );
+            amount.saturating_sub(deposit)
+        };
+
+        if !refund.is_zero() {
+            Promise::new(env::predecessor_account_id()).transfer(refund);
+        }
+
+        self.storage_balance_of(account_id).unwrap()
+    }
+
+    #[payable]
+    pub fn storage_withdraw(&mut self, amount: Option<NearToken>) -> StorageBalance {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+
+        let available = self
+            .storage_balance_of(account_id.clone())
+            .unwrap_or_else(|| panic!("The account is not registered"))
+            .available;
+        let amount = amount.unwrap_or(available);
+        require!(
+            amount <= available,
+            "The amount is greater than the available storage balance"
+        );
+
+        if !amount.is_zero() {
+            let storage = self.storage_deposits.get_mut(&account_id).unwrap();
+            storage.deposit = storage.deposit.saturating_sub(amount);
+            Promise::new(account_id.clone()).transfer(amount);
+        }
+
+        self.storage_balance_of(account_id).unwrap()
+    }
+
+    /// Unregisters the caller and returns their storage deposit. Accounts with locked balances,
+    /// any other storage in use, vesting schedules or withdrawals in flight cannot be
+    /// unregistered. `force` is not supported, as it would burn their tokens or leave their
+    /// records unpaid.
+    #[payable]
+    pub fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+        assert_one_yocto();
+        require!(
+            force != Some(true),
+            "Forced unregistration is not supported"
+        );
+        let account_id = env::predecessor_account_id();
+
+        let Some(storage) = self.storage_deposits.get(&account_id) else {
+            log!("The account is not registered");
+            return false;
+        };
+        let used_bytes = storage.used_bytes;
+        require!(
+            self.balances.get(&account_id).is_none() && self.nfts.get(&account_id).is_none(),
+            "Cannot unregister an account with locked balances"
+        );
+        require!(
+            used_bytes == STORAGE_REGISTRATION_BYTES,
+            "Cannot unregister an account that still uses storage"
+        );
+        require!(
+            !self.account_commitments.contains_key(&account_id),
+            "Cannot unregister an account with vesting schedules or withdrawals in flight"
+        );
+        require!(
+            self.reward_pools.keys().all(|token_id| !self
+                .reward_stakes
+                .contains_key(&(account_id.clone(), token_id.clone()))),
+            "Cannot unregister an account with unclaimed rewards"
+        );
+
+        let storage = self.storage_deposits.remove(&account_id).unwrap();
+        if !storage.deposit.is_zero() {
+            Promise::new(account_id).transfer(storage.deposit);
+        }
+
+        true
+    }
+
+    pub fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+        self.storage_deposits
+            .get(&account_id)
+            .map(|storage| StorageBalance {
+                total: storage.deposit,
+                available: storage
+                    .deposit
+                    .saturating_sub(storage_cost(storage.used_bytes)),
+            })
+    }
+
+    /// The minimum covers the registration plus a first token entry.
+    pub fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        StorageBalanceBounds {
+            min: storage_cost(
+                STORAGE_REGISTRATION_BYTES + ACCOUNT_MAP_STORAGE_BYTES + TOKEN_ENTRY_STORAGE_BYTES,
+            ),
+            max: None,
+        }
+    }
+}
+
+impl Contract {
+    /// Whether an account is registered and has storage left for a balance of the token.
+    pub(crate) fn internal_has_storage_for(&self, account_id: &AccountId, token_id: &str) -> bool {
+        self.internal_has_storage_for_bytes(account_id, token_id, 0)
+    }
+
+    /// Whether an account can pay for holding a token and `extra_bytes` more.
+    pub(crate) fn internal_has_storage_for_bytes(
+        &self,
+        account_id: &AccountId,
+        token_id: &str,
+        extra_bytes: u64,
+    ) -> bool {
+        let Some(storage) = self.storage_deposits.get(account_id) else {
+            return false;
+        };
+
+        if is_nft_token_id(token_id) {
+            let needed_bytes = match self.nfts.get(account_id) {
+                None => ACCOUNT_MAP_STORAGE_BYTES + NFT_ENTRY_STORAGE_BYTES,
+                Some(_) => NFT_ENTRY_STORAGE_BYTES,
+            };
+            return storage_cost(storage.used_bytes + needed_bytes + extra_bytes)
+                <= storage.deposit;
+        }
+
+        let mut needed_bytes = match self.balances.get(account_id) {
+            None => ACCOUNT_MAP_STORAGE_BYTES + TOKEN_ENTRY_STORAGE_BYTES,
+            Some(tokens) if !tokens.contains_key(token_id) => TOKEN_ENTRY_STORAGE_BYTES,
+            Some(_) => 0,
+        };
+        // A new balance of a staked token also records a stake in its reward pool
+        if needed_bytes > 0 && self.reward_pools.contains_key(token_id) {
+            needed_bytes += STAKE_STORAGE_BYTES;
+        }
+
+        storage_cost(storage.used_bytes + needed_bytes + extra_bytes) <= storage.deposit
+    }
+
+    /// Charges bytes to an account's storage. Balances restored by callbacks are never dropped,
+    /// so an account that is no longer registered is registered again without a deposit.
+    pub(crate) fn internal_use_storage(&mut self, account_id: &AccountId, bytes: u64) {
+        match self.storage_deposits.get_mut(account_id) {
+            Some(storage) => storage.used_bytes += bytes,
+            None => {
+                self.storage_deposits.insert(
+                    account_id.clone(),
+                    AccountStorage {
+                        deposit: NearToken::from_yoctonear(0),
+                        used_bytes: STORAGE_REGISTRATION_BYTES + bytes,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Charges an account the bytes storage grew by since `initial_storage`, or credits back the
+    /// bytes it shrank by. The collections written to must be flushed first, so their writes
+    /// show up in `env::storage_usage()`.
+    pub(crate) fn internal_settle_storage(&mut self, account_id: &AccountId, initial_storage: u64) {
+        let storage_usage = env::storage_usage();
+        if storage_usage > initial_storage {
+            self.internal_use_storage(account_id, storage_usage - initial_storage);
+        } else if storage_usage < initial_storage {
+            self.internal_release_storage(account_id, initial_storage - storage_usage);
+        }
+    }
+
+    /// Records a vesting schedule or withdrawal in flight that keeps an account registered.
+    pub(crate) fn internal_add_commitment(&mut self, account_id: &AccountId) {
+        let count = self
+            .account_commitments
+            .get(account_id)
+            .copied()
+            .unwrap_or(0);
+        self.account_commitments
+            .insert(account_id.clone(), count + 1);
+    }
+
+    /// Drops a commitment recorded with `internal_add_commitment` once it has ended.
+    pub(crate) fn internal_remove_commitment(&mut self, account_id: &AccountId) {
+        match self.account_commitments.get(account_id).copied() {
+            Some(count) if count > 1 => {
+                self.account_commitments
+                    .insert(account_id.clone(), count - 1);
+            }
+            _ => {
+                self.account_commitments.remove(account_id);
+            }
+        }
+    }
+
+    /// Credits bytes that are no longer used back to an account's storage, never below its
+    /// registration.
+    pub(crate) fn internal_release_storage(&mut self, account_id: &AccountId, bytes: u64) {
+        if let Some(storage) = self.storage_deposits.get_mut(account_id) {
+            storage.used_bytes = storage
+                .used_bytes
+                .saturating_sub(bytes)
+                .max(STORAGE_REGISTRATION_BYTES);
+        }
+    }
+}
+
+fn storage_cost(bytes: u64) -> NearToken {
+    env::storage_byte_cost().saturating_mul(bytes as u128)
+}
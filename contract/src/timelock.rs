@@ -0,0 +1,154 @@
+use near_sdk::json_types::{U128, U64};
+use near_sdk::{env, near, AccountId};
+
+use crate::events::ContractEvent;
+use crate::storage::{LOCK_ENTRY_STORAGE_BYTES, LOT_STORAGE_BYTES};
+use crate::token_id::TokenId;
+use crate::{Contract, ContractExt};
+
+/// Longest lock a deposit can choose, about four years in nanoseconds.
+pub const MAX_LOCK_DURATION: u64 = 4 * 365 * 24 * 60 * 60 * 1_000_000_000;
+/// Most lots an account can hold of a single token at once.
+pub const MAX_LOTS_PER_TOKEN: usize = 10;
+
+/// Part of a balance deposited with a lock, which cannot leave the balance before `unlock_at`.
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct Lot {
+    pub amount: U128,
+    /// Block timestamp in nanoseconds from which the lot can be withdrawn.
+    pub unlock_at: U64,
+}
+
+#[near(serializers = [json])]
+pub struct LockedBalance {
+    pub locked: U128,
+    pub unlocked: U128,
+}
+
+#[near]
+impl Contract {
+    /// Splits an account's balance of a token into the amount still locked and the amount it
+    /// can withdraw or transfer.
+    pub fn get_locked_balance(&self, account_id: AccountId, token_id: TokenId) -> LockedBalance {
+        let token_id = token_id.to_string();
+        let balance = self
+            .internal_available_balance(&account_id, &token_id)
+            .unwrap_or(0);
+        let locked = self.internal_locked_amount(&account_id, &token_id);
+
+        LockedBalance {
+            locked: U128(locked),
+            unlocked: U128(balance.saturating_sub(locked)),
+        }
+    }
+
+    /// Lots of a token an account still has locked, with their unlock times.
+    pub fn get_lots_for_account(&self, account_id: AccountId, token_id: TokenId) -> Vec<Lot> {
+        let now = env::block_timestamp();
+        self.locks
+            .get(&(account_id, token_id.to_string()))
+            .map(|lots| {
+                lots.iter()
+                    .filter(|lot| lot.unlock_at.0 > now)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Contract {
+    /// Amount of a token an account cannot move yet.
+    pub(crate) fn internal_locked_amount(&self, account_id: &AccountId, token_id: &str) -> u128 {
+        let now = env::block_timestamp();
+        self.locks
+            .get(&(account_id.clone(), token_id.to_string()))
+            .map_or(0, |lots| {
+                lots.iter()
+                    .filter(|lot| lot.unlock_at.0 > now)
+                    .map(|lot| lot.amount.0)
+                    .sum()
+            })
+    }
+
+    /// Checks that a locked deposit can add a lot, returning why it is refused.
+    pub(crate) fn check_lot(
+        &self,
+        account_id: &AccountId,
+        token_id: &str,
+    ) -> Result<(), &'static str> {
+        let now = env::block_timestamp();
+        let (active_lots, needed_bytes) =
+            match self.locks.get(&(account_id.clone(), token_id.to_string())) {
+                Some(lots) => (
+                    lots.iter().filter(|lot| lot.unlock_at.0 > now).count(),
+                    LOT_STORAGE_BYTES,
+                ),
+                None => (0, LOCK_ENTRY_STORAGE_BYTES + LOT_STORAGE_BYTES),
+            };
+
+        if active_lots >= MAX_LOTS_PER_TOKEN {
+            return Err("the account has too many locked lots of the token");
+        }
+        if !self.internal_has_storage_for_bytes(account_id, token_id, needed_bytes) {
+            return Err("the account has not registered enough storage for the lock");
+        }
+        Ok(())
+    }
+
+    /// Locks `amount` of an account's balance of a token until `unlock_at`.
+    pub(crate) fn internal_add_lot(
+        &mut self,
+        account_id: &AccountId,
+        token_id: &str,
+        amount: u128,
+        unlock_at: u64,
+    ) {
+        self.internal_prune_lots(account_id, token_id);
+
+        let initial_storage = env::storage_usage();
+        let key = (account_id.clone(), token_id.to_string());
+        if self.locks.get(&key).is_none() {
+            self.locks.insert(key.clone(), Vec::new());
+        }
+        self.locks.get_mut(&key).unwrap().push(Lot {
+            amount: U128(amount),
+            unlock_at: U64(unlock_at),
+        });
+
+        // Write the lots now, so the storage they use can be measured
+        self.locks.flush();
+        self.internal_settle_storage(account_id, initial_storage);
+
+        ContractEvent::Locked {
+            account_id: account_id.clone(),
+            token_id: token_id.to_string(),
+            amount: U128(amount),
+            unlock_at: U64(unlock_at),
+        }
+        .emit();
+    }
+
+    /// Drops the lots that have unlocked, crediting the storage they freed back.
+    pub(crate) fn internal_prune_lots(&mut self, account_id: &AccountId, token_id: &str) {
+        let key = (account_id.clone(), token_id.to_string());
+        let Some(lots) = self.locks.get_mut(&key) else {
+            return;
+        };
+
+        let now = env::block_timestamp();
+        let count = lots.len();
+        lots.retain(|lot| lot.unlock_at.0 > now);
+        if lots.len() == count {
+            return;
+        }
+
+        let initial_storage = env::storage_usage();
+        if lots.is_empty() {
+            self.locks.remove(&key);
+        }
+        self.locks.flush();
+        self.internal_settle_storage(account_id, initial_storage);
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt;
+use std::str::FromStr;
+
+use near_sdk::{near, AccountId};
+
+use crate::{Contract, ContractExt};
+
+/// Token Id of an asset held by the contract, in the format used by intents.near or, for assets
+/// locked directly, in the contract's own format. Token Ids are passed around as strings, for
+/// example `nep141:usdc.near`, and are validated when they are deserialized.
+#[near(serializers = [json])]
+#[serde(try_from = "String", into = "String")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenId {
+    /// `nep141:<contract>`, a NEP-141 token wrapped in intents.near.
+    Nep141 { contract_id: AccountId },
+    /// `nep245:<contract>:<token id>`, a NEP-245 token wrapped in intents.near.
+    Nep245 {
+        contract_id: AccountId,
+        token_id: String,
+    },
+    /// `nep171:<contract>:<token id>`, an NFT wrapped in intents.near.
+    Nep171 {
+        contract_id: AccountId,
+        token_id: String,
+    },
+    /// `ft:<contract>`, a NEP-141 token locked directly with `ft_transfer_call`.
+    Ft { contract_id: AccountId },
+    /// `nft:<contract>:<token id>`, an NFT locked directly with `nft_transfer_call`.
+    Nft {
+        contract_id: AccountId,
+        token_id: String,
+    },
+}
+
+impl TokenId {
+    /// Whether the token is held in the intents contract, and so moved with `mt_transfer`.
+    pub fn is_intents(&self) -> bool {
+        matches!(
+            self,
+            Self::Nep141 { .. } | Self::Nep245 { .. } | Self::Nep171 { .. }
+        )
+    }
+
+    pub fn is_nft(&self) -> bool {
+        matches!(self, Self::Nep171 { .. } | Self::Nft { .. })
+    }
+
+    /// Contract of the underlying asset.
+    pub fn contract_id(&self) -> &AccountId {
+        match self {
+            Self::Nep141 { contract_id }
+            | Self::Nep245 { contract_id, .. }
+            | Self::Nep171 { contract_id, .. }
+            | Self::Ft { contract_id }
+            | Self::Nft { contract_id, .. } => contract_id,
+        }
+    }
+}
+
+impl FromStr for TokenId {
+    type Err = String;
+
+    fn from_str(token_id: &str) -> Result<Self, Self::Err> {
+        let parse_contract = |contract_id: &str| {
+            contract_id
+                .parse::<AccountId>()
+                .map_err(|_| format!("Invalid contract in token Id {}", token_id))
+        };
+        let parse_contract_token = |rest: &str| {
+            let (contract_id, id) = rest
+                .split_once(':')
+                .filter(|(_, id)| !id.is_empty())
+                .ok_or_else(|| format!("Missing token in token Id {}", token_id))?;
+            Ok::<_, String>((parse_contract(contract_id)?, id.to_string()))
+        };
+
+        let Some((standard, rest)) = token_id.split_once(':') else {
+            return Err(format!("Invalid token Id {}", token_id));
+        };
+
+        match standard {
+            "nep141" => Ok(Self::Nep141 {
+                contract_id: parse_contract(rest)?,
+            }),
+            "nep245" => parse_contract_token(rest).map(|(contract_id, token_id)| Self::Nep245 {
+                contract_id,
+                token_id,
+            }),
+            "nep171" => parse_contract_token(rest).map(|(contract_id, token_id)| Self::Nep171 {
+                contract_id,
+                token_id,
+            }),
+            "ft" => Ok(Self::Ft {
+                contract_id: parse_contract(rest)?,
+            }),
+            "nft" => parse_contract_token(rest).map(|(contract_id, token_id)| Self::Nft {
+                contract_id,
+                token_id,
+            }),
+            _ => Err(format!("Unknown token standard in token Id {}", token_id)),
+        }
+    }
+}
+
+impl fmt::Display for TokenId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nep141 { contract_id } => write!(f, "nep141:{}", contract_id),
+            Self::Nep245 {
+                contract_id,
+                token_id,
+            } => write!(f, "nep245:{}:{}", contract_id, token_id),
+            Self::Nep171 {
+                contract_id,
+                token_id,
+            } => write!(f, "nep171:{}:{}", contract_id, token_id),
+            Self::Ft { contract_id } => write!(f, "ft:{}", contract_id),
+            Self::Nft {
+                contract_id,
+                token_id,
+            } => write!(f, "nft:{}:{}", contract_id, token_id),
+        }
+    }
+}
+
+impl TryFrom<String> for TokenId {
+    type Error = String;
+
+    fn try_from(token_id: String) -> Result<Self, Self::Error> {
+        token_id.parse()
+    }
+}
+
+impl From<TokenId> for String {
+    fn from(token_id: TokenId) -> Self {
+        token_id.to_string()
+    }
+}
+
+/// Whether a ledger token Id is an NFT, either locked directly or wrapped in intents.near.
+pub fn is_nft_token_id(token_id: &str) -> bool {
+    token_id
+        .parse::<TokenId>()
+        .is_ok_and(|token_id| token_id.is_nft())
+}
+
+#[near]
+impl Contract {
+    pub fn is_valid_token_id(&self, token_id: String) -> bool {
+        token_id.parse::<TokenId>().is_ok()
+    }
+
+    /// Contract of the asset behind a token Id.
+    pub fn get_token_contract(&self, token_id: TokenId) -> AccountId {
+        token_id.contract_id().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_token_ids() {
+        for token_id in [
+            "nep141:usdc.near",
+            "nep245:mt.near:token-1",
+            "nep171:nft.near:1:2",
+            "ft:usdc.near",
+            "nft:nft.near:1",
+        ] {
+            assert_eq!(token_id.parse::<TokenId>().unwrap().to_string(), token_id);
+        }
+
+        assert_eq!(
+            "nep171:nft.near:1:2".parse::<TokenId>().unwrap(),
+            TokenId::Nep171 {
+                contract_id: "nft.near".parse().unwrap(),
+                token_id: "1:2".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_token_ids() {
+        for token_id in [
+            "",
+            "1",
+            "usdc.near",
+            "nep141:",
+            "nep141:Not An Account",
+            "nep245:mt.near",
+            "nep245:mt.near:",
+            "nep171:nft.near",
+            "erc20:0x1234",
+        ] {
+            assert!(token_id.parse::<TokenId>().is_err(), "{}", token_id);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use near_sdk::json_types::U128;
+use near_sdk::{env, near, require, AccountId, Gas, PromiseOrValue};
+
+use crate::events::{ContractEvent, Nep245Event};
+use crate::token_id::TokenId;
+use crate::{Contract, ContractExt};
+
+/// Gas kept for `mt_resolve_transfer` when calling the receiver of a transfer call.
+pub const RESOLVE_TRANSFER_GAS: Gas = Gas::from_tgas(10);
+/// Least gas attached to the receiver's `mt_on_transfer`, it also gets all the unused gas.
+pub const TRANSFER_CALL_GAS: Gas = Gas::from_tgas(30);
+
+#[near]
+impl Contract {
+    /// Moves `amount` of a locked token from the caller to `receiver_id` inside the contract.
+    /// The receiver must have registered storage for the token. Same as `mt_transfer`, it
+    /// requires 1 yoctoNEAR.
+    #[payable]
+    pub fn transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        memo: Option<String>,
+    ) {
+        self.mt_transfer(receiver_id, token_id, amount, None, memo);
+    }
+
+    /// Moves `amount` of a locked token from the caller to the `receiver_id` contract and calls
+    /// its `mt_on_transfer` with `msg`. The amount the receiver returns as its refund is moved
+    /// back to the caller. Same as `mt_transfer_call`, it requires 1 yoctoNEAR.
+    #[payable]
+    pub fn transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        memo: Option<String>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        self.mt_transfer_call(receiver_id, token_id, amount, None, memo, msg)
+    }
+}
+
+impl Contract {
+    pub(crate) fn internal_transfer(
+        &mut self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_id: &TokenId,
+        amount: u128,
+        memo: Option<String>,
+    ) {
+        require!(sender_id != receiver_id, "Cannot transfer to yourself");
+        require!(!token_id.is_nft(), "NFTs cannot be transferred");
+        require!(amount > 0, "Cannot transfer 0 tokens");
+        self.assert_withdrawal_allowed(sender_id, receiver_id, false);
+
+        let token_id = token_id.to_string();
+        require!(
+            self.internal_has_storage_for(receiver_id, &token_id),
+            "The receiver has not registered enough storage"
+        );
+
+        self.internal_withdraw(sender_id, &token_id, amount);
+        self.internal_deposit(receiver_id, &token_id, amount);
+
+        Nep245Event::transfer(sender_id, receiver_id, &token_id, amount, memo.clone()).emit();
+        ContractEvent::Transfer {
+            sender_id: sender_id.clone(),
+            receiver_id: receiver_id.clone(),
+            token_id,
+            amount: U128(amount),
+            memo,
+        }
+        .emit();
+    }
+
+    /// Moves the refund requested by the receiver of a transfer call back to the sender, as far
+    /// as the receiver still holds it unlocked. Returns the amount moved back.
+    pub(crate) fn internal_resolve_transfer(
+        &mut self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_id: &str,
+        amount: u128,
+        requested: u128,
+    ) -> u128 {
+        let unlocked = self
+            .internal_available_balance(receiver_id, token_id)
+            .unwrap_or(0)
+            .saturating_sub(self.internal_locked_amount(receiver_id, token_id));
+        let refund = requested.min(amount).min(unlocked);
+
+        if refund > 0 {
+            self.internal_withdraw(receiver_id, token_id, refund);
+            self.internal_deposit(sender_id, token_id, refund);
+            Nep245Event::transfer(receiver_id, sender_id, token_id, refund, None).emit();
+            ContractEvent::Transfer {
+                sender_id: receiver_id.clone(),
+                receiver_id: sender_id.clone(),
+                token_id: token_id.to_string(),
+                amount: U128(refund),
+                memo: None,
+            }
+            .emit();
+        }
+
+        refund
+    }
+}
@@ -0,0 +1,264 @@
+use near_sdk::borsh::{self, BorshDeserialize};
+use near_sdk::store::{IterableMap, LookupMap};
+use near_sdk::{env, log, near, require, AccountId, Gas, IntoStorageKey, NearToken, Promise};
+use near_sdk_contract_tools::owner::*;
+
+use crate::admin::Role;
+use crate::{account_balances_key, Contract, ContractExt, StorageKey};
+
+/// Gas kept by `upgrade` for deploying the code, the rest is attached to `migrate`.
+pub const UPGRADE_GAS: Gas = Gas::from_tgas(20);
+
+/// Layouts the contract state has been stored in. The version lives under its own storage key
+/// so it can be read before the state itself, state without a version is `V1`. Every change to
+/// the layout of the state adds a version and freezes the previous layout below.
+#[near(serializers = [borsh, json])]
+pub enum StateVersion {
+    V1,
+    V2,
+}
+
+impl StateVersion {
+    pub const CURRENT: Self = Self::V2;
+}
+
+/// State layout of the first release, which only tracked balances.
+#[near(serializers = [borsh])]
+pub struct ContractV1 {
+    pub intents_contract_id: AccountId,
+    pub balances: LookupMap<AccountId, IterableMap<String, u128>>,
+}
+
+/// Contract state as stored by each version.
+pub enum VersionedState {
+    V1(ContractV1),
+    V2(Contract),
+}
+
+impl VersionedState {
+    pub fn read() -> Self {
+        match read_state_version() {
+            StateVersion::V1 => Self::V1(read_state()),
+            StateVersion::V2 => Self::V2(read_state()),
+        }
+    }
+}
+
+fn read_state<T: BorshDeserialize>() -> T {
+    env::state_read().unwrap_or_else(|| panic!("Contract state not found"))
+}
+
+#[near]
+impl Contract {
+    /// Deploys the code passed as the raw input of the call and migrates the state to it.
+    /// Only callable by the owner.
+    pub fn upgrade(&mut self) -> Promise {
+        Self::require_owner();
+        let code = env::input().unwrap_or_else(|| panic!("No code to deploy"));
+        let migrate_gas = env::prepaid_gas()
+            .saturating_sub(env::used_gas())
+            .saturating_sub(UPGRADE_GAS);
+
+        Promise::new(env::current_account_id())
+            .deploy_contract(code)
+            .function_call(
+                "migrate".to_string(),
+                b"{}".to_vec(),
+                NearToken::from_yoctonear(0),
+                migrate_gas,
+            )
+    }
+
+    /// Migrates the stored state to the current layout. A first release contract has no owner,
+    /// so `owner_id` (the contract account by default) becomes its owner. Upgrade a first release
+    /// contract only while none of its withdrawals are in flight, as their callbacks changed.
+    #[init(ignore_state)]
+    #[private]
+    pub fn migrate(owner_id: Option<AccountId>) -> Self {
+        let contract = match VersionedState::read() {
+            VersionedState::V1(old) => {
+                // The first release kept no totals, they are only complete once every account
+                // has gone through `migrate_account_balances`
+                let mut contract = Self {
+                    balances: old.balances,
+                    totals_complete: false,
+                    ..Self::internal_new(old.intents_contract_id)
+                };
+                let owner_id = owner_id.unwrap_or_else(env::current_account_id);
+                Owner::init(&mut contract, &owner_id);
+                contract
+            }
+            VersionedState::V2(contract) => contract,
+        };
+
+        write_state_version(StateVersion::CURRENT);
+        contract
+    }
+
+    /// Moves the balances of accounts created before their prefixes were hashed under their
+    /// hashed prefix, adding them to the token totals the first release did not keep. Accounts
+    /// that were already moved are rebuilt in place, so this is safe to run more than once.
+    pub fn migrate_account_balances(&mut self, account_ids: Vec<AccountId>) {
+        self.assert_owner_or_role(&Role::Operator);
+
+        for account_id in account_ids {
+            let Some(mut tokens) = self.balances.remove(&account_id) else {
+                continue;
+            };
+            let entries: Vec<(String, u128)> = tokens.drain().collect();
+            let mut migrated = IterableMap::new(account_balances_key(&account_id));
+
+            // Two empty maps only serialize the same if they share a prefix
+            let legacy = borsh::to_vec(&tokens).unwrap() != borsh::to_vec(&migrated).unwrap();
+            drop(tokens);
+            if legacy {
+                for (token_id, amount) in entries.iter() {
+                    self.internal_increase_total(token_id, *amount);
+                }
+            }
+
+            migrated.extend(entries);
+            self.balances.insert(account_id, migrated);
+        }
+    }
+
+    /// Marks the token totals as complete once `migrate_account_balances` has been run for every
+    /// account of a first release contract, which allows rescuing surplus tokens again.
+    pub fn complete_account_migration(&mut self) {
+        self.assert_owner_or_role(&Role::Operator);
+        require!(!self.totals_complete, "Accounts are already migrated");
+        self.totals_complete = true;
+        log!("Account migration completed");
+    }
+
+    /// Whether the token totals cover every account, false after upgrading a first release
+    /// contract until `complete_account_migration` is called.
+    pub fn get_totals_complete(&self) -> bool {
+        self.totals_complete
+    }
+
+    pub fn get_state_version(&self) -> StateVersion {
+        read_state_version()
+    }
+}
+
+pub(crate) fn read_state_version() -> StateVersion {
+    env::storage_read(&StorageKey::StateVersion.into_storage_key())
+        .map(|bytes| {
+            StateVersion::try_from_slice(&bytes).unwrap_or_else(|_| panic!("Invalid state version"))
+        })
+        .unwrap_or(StateVersion::V1)
+}
+
+pub(crate) fn write_state_version(version: StateVersion) {
+    env::storage_write(
+        &StorageKey::StateVersion.into_storage_key(),
+        &borsh::to_vec(&version).unwrap(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::json_types::U128;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+
+    #[test]
+    fn migrates_balances_from_v1() {
+        testing_env!(VMContextBuilder::new()
+            .current_account_id(accounts(0))
+            .build());
+
+        // Store state the way the first release did
+        {
+            let mut old = ContractV1 {
+                intents_contract_id: "intents.near".parse().unwrap(),
+                balances: LookupMap::new(StorageKey::Balances),
+            };
+            let mut tokens: IterableMap<String, u128> = IterableMap::new(accounts(1).as_bytes());
+            tokens.insert("nep141:usdc.near".to_string(), 50);
+            old.balances.insert(accounts(1), tokens);
+            env::state_write(&old);
+        }
+
+        let contract = Contract::migrate(None);
+
+        assert_eq!(
+            contract
+                .get_token_balance_for_account(accounts(1), "nep141:usdc.near".parse().unwrap()),
+            Some(U128(50))
+        );
+        assert_eq!(
+            contract.get_intents_contract_id(),
+            "intents.near".parse::<AccountId>().unwrap()
+        );
+        assert_eq!(contract.own_get_owner(), Some(accounts(0)));
+        assert!(matches!(read_state_version(), StateVersion::V2));
+        assert!(!contract.get_totals_complete());
+    }
+
+    #[test]
+    #[should_panic(expected = "Token totals are incomplete until every account is migrated")]
+    fn rescue_waits_for_account_migration() {
+        testing_env!(VMContextBuilder::new()
+            .current_account_id(accounts(0))
+            .predecessor_account_id(accounts(0))
+            .build());
+        env::state_write(&ContractV1 {
+            intents_contract_id: "intents.near".parse().unwrap(),
+            balances: LookupMap::new(StorageKey::Balances),
+        });
+
+        let mut contract = Contract::migrate(None);
+        contract.rescue_surplus("nep141:usdc.near".parse().unwrap(), accounts(1), None);
+    }
+
+    #[test]
+    fn rescue_allowed_once_accounts_are_migrated() {
+        testing_env!(VMContextBuilder::new()
+            .current_account_id(accounts(0))
+            .predecessor_account_id(accounts(0))
+            .build());
+        env::state_write(&ContractV1 {
+            intents_contract_id: "intents.near".parse().unwrap(),
+            balances: LookupMap::new(StorageKey::Balances),
+        });
+
+        let mut contract = Contract::migrate(None);
+        contract.complete_account_migration();
+        assert!(contract.get_totals_complete());
+        contract.rescue_surplus("nep141:usdc.near".parse().unwrap(), accounts(1), None);
+    }
+
+    #[test]
+    fn migrates_legacy_account_prefixes() {
+        testing_env!(VMContextBuilder::new()
+            .current_account_id(accounts(0))
+            .predecessor_account_id(accounts(0))
+            .build());
+        let mut contract = Contract::new("intents.near".parse().unwrap(), None);
+
+        // Store a balances map under the raw account Id prefix of the first release
+        let mut tokens: IterableMap<String, u128> = IterableMap::new(accounts(1).as_bytes());
+        tokens.insert("nep141:usdc.near".to_string(), 50);
+        contract.balances.insert(accounts(1), tokens);
+
+        contract.migrate_account_balances(vec![accounts(1), accounts(2)]);
+        contract.migrate_account_balances(vec![accounts(1)]);
+
+        // The balance is only counted once in the token's total
+        assert_eq!(
+            contract.get_token_liabilities("nep141:usdc.near".parse().unwrap()),
+            U128(50)
+        );
+
+        assert_eq!(
+            contract.get_tokens_for_account(accounts(1), &None, &None),
+            vec![("nep141:usdc.near".to_string(), U128(50))]
+        );
+        assert!(contract
+            .get_tokens_for_account(accounts(2), &None, &None)
+            .is_empty());
+    }
+}
@@ -0,0 +1,239 @@
+use near_sdk::json_types::{U128, U64};
+use near_sdk::{env, near, require, AccountId, NearToken, Promise};
+
+use crate::events::{ContractEvent, Nep245Event};
+use crate::ext_mt::*;
+use crate::storage::VESTING_STORAGE_BYTES;
+use crate::token_id::TokenId;
+use crate::{Contract, ContractExt};
+
+/// Tokens a funder moved out of their balance to vest linearly to a beneficiary. Nothing vests
+/// before `cliff`, then the amount vests linearly from `start` to `end`.
+#[near(serializers = [borsh, json])]
+#[derive(Clone)]
+pub struct VestingSchedule {
+    pub funder_id: AccountId,
+    pub beneficiary_id: AccountId,
+    pub token_id: String,
+    pub amount: U128,
+    pub claimed: U128,
+    pub start: U64,
+    pub cliff: U64,
+    pub end: U64,
+    /// Whether the funder can take back the amount that has not vested yet.
+    pub revocable: bool,
+    /// Set once the schedule is revoked, `amount` then only holds what had vested.
+    pub revoked: bool,
+}
+
+#[near(serializers = [json])]
+pub struct VestingView {
+    pub schedule: VestingSchedule,
+    pub vested: U128,
+    pub claimable: U128,
+}
+
+impl VestingSchedule {
+    /// Amount vested at `timestamp`.
+    pub fn vested(&self, timestamp: u64) -> u128 {
+        let amount = self.amount.0;
+        if self.revoked || timestamp >= self.end.0 {
+            return amount;
+        }
+        if timestamp < self.cliff.0 {
+            return 0;
+        }
+
+        // Split the product so it cannot overflow
+        let duration = (self.end.0 - self.start.0) as u128;
+        let elapsed = (timestamp - self.start.0) as u128;
+        amount / duration * elapsed + amount % duration * elapsed / duration
+    }
+}
+
+#[near]
+impl Contract {
+    /// Moves `amount` of an intents token from the caller's unlocked balance into a schedule
+    /// vesting it to `beneficiary_id`, and returns the schedule's Id. The caller pays the
+    /// schedule's storage until it is fully claimed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_vesting(
+        &mut self,
+        beneficiary_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        start: U64,
+        cliff: U64,
+        end: U64,
+        revocable: bool,
+    ) -> u64 {
+        let funder_id = env::predecessor_account_id();
+        require!(
+            token_id.is_intents() && !token_id.is_nft(),
+            "Only fungible intents tokens can be vested"
+        );
+        require!(amount.0 > 0, "Cannot vest 0 tokens");
+        require!(
+            start.0 <= cliff.0 && cliff.0 <= end.0 && start.0 < end.0,
+            "The schedule must start before it ends, with the cliff in between"
+        );
+        let token_id = token_id.to_string();
+        require!(
+            self.internal_has_storage_for_bytes(&funder_id, &token_id, VESTING_STORAGE_BYTES),
+            "The funder has not registered enough storage"
+        );
+
+        self.internal_withdraw(&funder_id, &token_id, amount.0);
+        Nep245Event::burn(&funder_id, &token_id, amount.0).emit();
+
+        self.internal_add_commitment(&beneficiary_id);
+
+        let initial_storage = env::storage_usage();
+        let schedule_id = self.next_vesting_id;
+        self.next_vesting_id += 1;
+        self.vestings.insert(
+            schedule_id,
+            VestingSchedule {
+                funder_id: funder_id.clone(),
+                beneficiary_id: beneficiary_id.clone(),
+                token_id: token_id.clone(),
+                amount,
+                claimed: U128(0),
+                start,
+                cliff,
+                end,
+                revocable,
+                revoked: false,
+            },
+        );
+        self.vestings.flush();
+        self.internal_settle_storage(&funder_id, initial_storage);
+
+        ContractEvent::VestingCreated {
+            schedule_id,
+            funder_id,
+            beneficiary_id,
+            token_id,
+            amount,
+        }
+        .emit();
+
+        schedule_id
+    }
+
+    /// Withdraws the vested amount not claimed yet to the beneficiary with `mt_transfer`, like
+    /// `withdraw_token`. A failed transfer is restored to the beneficiary's balance.
+    pub fn claim_vested(&mut self, schedule_id: u64) -> Promise {
+        let schedule = self
+            .vestings
+            .get(&schedule_id)
+            .cloned()
+            .unwrap_or_else(|| panic!("Vesting schedule not found"));
+        let beneficiary_id = env::predecessor_account_id();
+        require!(
+            schedule.beneficiary_id == beneficiary_id,
+            "Only the beneficiary can claim"
+        );
+        self.assert_withdrawal_allowed(&beneficiary_id, &beneficiary_id, false);
+
+        let amount = schedule.vested(env::block_timestamp()) - schedule.claimed.0;
+        require!(amount > 0, "Nothing to claim");
+
+        let claimed = schedule.claimed.0 + amount;
+        if claimed == schedule.amount.0 {
+            self.internal_remove_vesting(schedule_id, &schedule.funder_id);
+            self.internal_remove_commitment(&beneficiary_id);
+        } else {
+            self.vestings.get_mut(&schedule_id).unwrap().claimed = U128(claimed);
+        }
+
+        ContractEvent::VestingClaimed {
+            schedule_id,
+            beneficiary_id: beneficiary_id.clone(),
+            amount: U128(amount),
+        }
+        .emit();
+        // The claimed amount passes through the beneficiary's receipts on its way out
+        Nep245Event::mint(&beneficiary_id, &schedule.token_id, amount).emit();
+        let nonce = self.internal_start_withdrawal(
+            &beneficiary_id,
+            beneficiary_id.as_str(),
+            &schedule.token_id,
+            amount,
+        );
+
+        mt_contract::ext(self.intents_contract_id.clone())
+            .with_attached_deposit(NearToken::from_yoctonear(1))
+            .with_static_gas(self.gas.mt_transfer)
+            .mt_transfer(beneficiary_id, schedule.token_id, U128(amount))
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(self.gas.callback)
+                    .withdraw_callback(nonce),
+            )
+    }
+
+    /// Ends a revocable schedule, returning the amount that has not vested yet to the funder's
+    /// balance. The beneficiary can still claim what had vested. Returns the amount returned.
+    pub fn revoke_vesting(&mut self, schedule_id: u64) -> U128 {
+        let funder_id = env::predecessor_account_id();
+        let schedule = self
+            .vestings
+            .get_mut(&schedule_id)
+            .unwrap_or_else(|| panic!("Vesting schedule not found"));
+        require!(
+            schedule.funder_id == funder_id,
+            "Only the funder can revoke"
+        );
+        require!(schedule.revocable, "The schedule is not revocable");
+        require!(!schedule.revoked, "The schedule is already revoked");
+
+        let vested = schedule.vested(env::block_timestamp());
+        let unvested = schedule.amount.0 - vested;
+        schedule.amount = U128(vested);
+        schedule.revoked = true;
+        let token_id = schedule.token_id.clone();
+        let beneficiary_id = schedule.beneficiary_id.clone();
+        let fully_claimed = schedule.claimed.0 == vested;
+
+        if fully_claimed {
+            self.internal_remove_vesting(schedule_id, &funder_id);
+            self.internal_remove_commitment(&beneficiary_id);
+        }
+        if unvested > 0 {
+            self.internal_deposit(&funder_id, &token_id, unvested);
+            Nep245Event::mint(&funder_id, &token_id, unvested).emit();
+        }
+
+        ContractEvent::VestingRevoked {
+            schedule_id,
+            funder_id,
+            amount: U128(unvested),
+        }
+        .emit();
+
+        U128(unvested)
+    }
+
+    /// A vesting schedule with its vested and claimable amounts, `None` once fully claimed.
+    pub fn get_vesting(&self, schedule_id: u64) -> Option<VestingView> {
+        self.vestings.get(&schedule_id).map(|schedule| {
+            let vested = schedule.vested(env::block_timestamp());
+            VestingView {
+                schedule: schedule.clone(),
+                vested: U128(vested),
+                claimable: U128(vested - schedule.claimed.0),
+            }
+        })
+    }
+}
+
+impl Contract {
+    /// Removes a schedule that has ended, crediting the storage it freed back to its funder.
+    fn internal_remove_vesting(&mut self, schedule_id: u64, funder_id: &AccountId) {
+        let initial_storage = env::storage_usage();
+        self.vestings.remove(&schedule_id);
+        self.vestings.flush();
+        self.internal_settle_storage(funder_id, initial_storage);
+    }
+}
@@ -0,0 +1,257 @@
+//! Minimal stand-in for the intents.near multi-token ledger used by the sandbox tests.
+//!
+//! Tokens are minted under any intents token Id, for example `nep141:usdc.near`, so the tests use
+//! the same Ids as mainnet. `ft_withdraw` only bridges POA `*.omft.near` tokens, burning them,
+//! and there is no `nft_transfer`, so other withdrawals relying on them fail and are rolled back.
+//!
+//! Deployed on its own, it also stands in for a contract receiving tokens with `mt_on_transfer`,
+//! keeping them all unless `msg` is the amount of each token to refund.
+
+use near_sdk::json_types::U128;
+use near_sdk::store::LookupMap;
+use near_sdk::{
+    assert_one_yocto, env, ext_contract, near, require, AccountId, Gas, PanicOnDefault,
+    PromiseError, PromiseOrValue,
+};
+
+const RESOLVE_TRANSFER_GAS: Gas = Gas::from_tgas(15);
+
+#[ext_contract(mt_receiver)]
+#[allow(dead_code)]
+trait MtReceiver {
+    fn mt_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_ids: Vec<AccountId>,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>>;
+}
+
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct Contract {
+    balances: LookupMap<(AccountId, String), u128>,
+}
+
+#[near]
+impl Contract {
+    #[init]
+    pub fn new() -> Self {
+        Self {
+            balances: LookupMap::new(b"b"),
+        }
+    }
+
+    pub fn mt_mint(&mut self, owner_id: AccountId, token_id: String, amount: U128) {
+        self.internal_deposit(&owner_id, &token_id, amount.0);
+    }
+
+    pub fn mt_balance_of(&self, account_id: AccountId, token_id: String) -> U128 {
+        U128(self.internal_balance(&account_id, &token_id))
+    }
+
+    pub fn mt_batch_balance_of(&self, account_id: AccountId, token_ids: Vec<String>) -> Vec<U128> {
+        token_ids
+            .iter()
+            .map(|token_id| U128(self.internal_balance(&account_id, token_id)))
+            .collect()
+    }
+
+    #[payable]
+    pub fn mt_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: String,
+        amount: U128,
+        approval: Option<(AccountId, u64)>,
+        memo: Option<String>,
+    ) {
+        self.mt_batch_transfer(
+            receiver_id,
+            vec![token_id],
+            vec![amount],
+            approval.map(|approval| vec![Some(approval)]),
+            memo,
+        );
+    }
+
+    #[payable]
+    #[allow(unused_variables)]
+    pub fn mt_batch_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+        approvals: Option<Vec<Option<(AccountId, u64)>>>,
+        memo: Option<String>,
+    ) {
+        assert_one_yocto();
+        let sender_id = env::predecessor_account_id();
+        self.internal_batch_transfer(&sender_id, &receiver_id, &token_ids, &amounts);
+    }
+
+    #[payable]
+    pub fn mt_transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: String,
+        amount: U128,
+        approval: Option<(AccountId, u64)>,
+        memo: Option<String>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        self.mt_batch_transfer_call(
+            receiver_id,
+            vec![token_id],
+            vec![amount],
+            approval.map(|approval| vec![Some(approval)]),
+            memo,
+            msg,
+        )
+    }
+
+    #[payable]
+    #[allow(unused_variables)]
+    pub fn mt_batch_transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+        approvals: Option<Vec<Option<(AccountId, u64)>>>,
+        memo: Option<String>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        assert_one_yocto();
+        let sender_id = env::predecessor_account_id();
+        self.internal_batch_transfer(&sender_id, &receiver_id, &token_ids, &amounts);
+
+        mt_receiver::ext(receiver_id.clone())
+            .mt_on_transfer(
+                sender_id.clone(),
+                vec![sender_id.clone(); token_ids.len()],
+                token_ids.clone(),
+                amounts.clone(),
+                msg,
+            )
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(RESOLVE_TRANSFER_GAS)
+                    .with_unused_gas_weight(0)
+                    .mt_resolve_transfer(sender_id, receiver_id, token_ids, amounts),
+            )
+            .into()
+    }
+
+    /// Burns the caller's `nep141:<token>` balance in place of bridging it and returns the amount
+    /// withdrawn. Only POA `*.omft.near` tokens can be bridged.
+    #[payable]
+    #[allow(unused_variables)]
+    pub fn ft_withdraw(
+        &mut self,
+        token: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+        memo: Option<String>,
+    ) -> U128 {
+        assert_one_yocto();
+        require!(
+            token.as_str().ends_with(".omft.near"),
+            "The token cannot be bridged"
+        );
+        let sender_id = env::predecessor_account_id();
+        self.internal_withdraw(&sender_id, &format!("nep141:{}", token), amount.0);
+        amount
+    }
+
+    /// Keeps every token it receives, or refunds the amount of each token given as `msg`.
+    #[allow(unused_variables)]
+    pub fn mt_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_ids: Vec<AccountId>,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        let refund: u128 = msg.parse().unwrap_or(0);
+        PromiseOrValue::Value(
+            amounts
+                .iter()
+                .map(|amount| U128(refund.min(amount.0)))
+                .collect(),
+        )
+    }
+
+    /// Moves the refunds the receiver asked for back to the sender, as far as the receiver still
+    /// holds them, and returns the amounts the receiver kept.
+    #[private]
+    pub fn mt_resolve_transfer(
+        &mut self,
+        #[callback_result] call_result: Result<Vec<U128>, PromiseError>,
+        sender_id: AccountId,
+        receiver_id: AccountId,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+    ) -> Vec<U128> {
+        let refunds = call_result
+            .ok()
+            .filter(|refunds| refunds.len() == amounts.len())
+            .unwrap_or_else(|| amounts.clone());
+
+        token_ids
+            .iter()
+            .zip(amounts.iter().zip(refunds))
+            .map(|(token_id, (amount, refund))| {
+                let refund = refund
+                    .0
+                    .min(amount.0)
+                    .min(self.internal_balance(&receiver_id, token_id));
+                if refund > 0 {
+                    self.internal_withdraw(&receiver_id, token_id, refund);
+                    self.internal_deposit(&sender_id, token_id, refund);
+                }
+                U128(amount.0 - refund)
+            })
+            .collect()
+    }
+}
+
+impl Contract {
+    fn internal_balance(&self, account_id: &AccountId, token_id: &str) -> u128 {
+        self.balances
+            .get(&(account_id.clone(), token_id.to_string()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn internal_deposit(&mut self, account_id: &AccountId, token_id: &str, amount: u128) {
+        let balance = self.internal_balance(account_id, token_id);
+        self.balances
+            .insert((account_id.clone(), token_id.to_string()), balance + amount);
+    }
+
+    fn internal_withdraw(&mut self, account_id: &AccountId, token_id: &str, amount: u128) {
+        let balance = self.internal_balance(account_id, token_id);
+        require!(balance >= amount, "Not enough balance");
+        self.balances
+            .insert((account_id.clone(), token_id.to_string()), balance - amount);
+    }
+
+    fn internal_batch_transfer(
+        &mut self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_ids: &[String],
+        amounts: &[U128],
+    ) {
+        require!(token_ids.len() == amounts.len(), "Invalid input length");
+        require!(sender_id != receiver_id, "Cannot transfer to self");
+        for (token_id, amount) in token_ids.iter().zip(amounts) {
+            require!(amount.0 > 0, "Cannot transfer 0 tokens");
+            self.internal_withdraw(sender_id, token_id, amount.0);
+            self.internal_deposit(receiver_id, token_id, amount.0);
+        }
+    }
+}
@@ -1,12 +1,18 @@
 use serde_json::json;
 mod utils;
-use near_workspaces::types::Gas;
+use near_workspaces::types::{Gas, NearToken};
 use utils::{
-    check_balance, create_subaccount, get_token_balance_for_account, get_tokens_for_account,
-    mint_token, register_account, transfer_call_tokens, transfer_tokens, withdraw_token,
+    batch_transfer_call_tokens, check_balance, create_subaccount, get_pending_balance_for_account,
+    get_token_balance_for_account, get_tokens_for_account, mint_token, register_token,
+    storage_deposit, transfer_call_tokens, transfer_tokens, withdraw_to_chain, withdraw_token,
+    withdraw_token_to, withdraw_tokens,
 };
 
-const MT_WASM_FILEPATH: &str = "./tests/multi_token.wasm";
+const MOCK_INTENTS_PATH: &str = "./tests/mock-intents";
+const V1_CONTRACT_PATH: &str = "./tests/v1-contract";
+const TOKEN_1: &str = "nep141:usdc.near";
+const TOKEN_2: &str = "nep245:multi.near:2";
+const BRIDGED_TOKEN: &str = "nep141:eth.omft.near";
 
 #[tokio::test]
 async fn test_contract_is_operational() -> Result<(), Box<dyn std::error::Error>> {
@@ -35,117 +41,155 @@ async fn test_contract_is_operational() -> Result<(), Box<dyn std::error::Error>
     assert!(res.is_success(), "Contract initialization failed {:?}", res);
 
     // Deploy the MT contract
-    let mt_wasm = std::fs::read(MT_WASM_FILEPATH)?;
+    let mt_wasm = near_workspaces::compile_project(MOCK_INTENTS_PATH).await?;
     let mt_contract = mt_contract_account.deploy(&mt_wasm).await?.unwrap();
 
-    // Initialize the MT contract with mt_admin as owner
-    res = mt_contract
-        .call("new_default_meta")
-        .args_json(json!({
-            "owner_id": mt_admin.id()
-        }))
-        .transact()
-        .await?;
-
+    // Initialize the MT contract
+    res = mt_contract.call("new").transact().await?;
     assert!(
         res.is_success(),
         "MT contract initialization failed {:?}",
         res
     );
 
-    // Mint two tokens
-    res = mint_token(&mt_admin, &mt_contract, "Token 1", 1000).await?;
+    // Mint two tokens to mt_admin
+    res = mint_token(&mt_admin, &mt_contract, TOKEN_1, 1000).await?;
     assert!(res.is_success(), "Token 1 minting failed {:?}", res);
-    res = mint_token(&mt_admin, &mt_contract, "Token 2", 1000).await?;
+    res = mint_token(&mt_admin, &mt_contract, TOKEN_2, 1000).await?;
     assert!(res.is_success(), "Token 2 minting failed {:?}", res);
 
     // Deploy another MT contract
-    let faulty_mt_wasm = std::fs::read(MT_WASM_FILEPATH)?;
-    let faulty_mt_contract = faulty_mt_contract_account
-        .deploy(&faulty_mt_wasm)
-        .await?
-        .unwrap();
-
-    // Initialize the faulty MT contract with mt_admin as owner
-    res = faulty_mt_contract
-        .call("new_default_meta")
-        .args_json(json!({
-            "owner_id": mt_admin.id()
-        }))
-        .transact()
-        .await?;
+    let faulty_mt_contract = faulty_mt_contract_account.deploy(&mt_wasm).await?.unwrap();
 
+    // Initialize the faulty MT contract and mint the same token in it
+    res = faulty_mt_contract.call("new").transact().await?;
     assert!(
         res.is_success(),
         "Faulty MT contract initialization failed {:?}",
         res
     );
-
-    // Mint a token in the faulty MT contract
-    res = mint_token(&mt_admin, &faulty_mt_contract, "Faulty Token", 1000).await?;
+    res = mint_token(&mt_admin, &faulty_mt_contract, TOKEN_1, 1000).await?;
     assert!(res.is_success(), "Faulty Token minting failed {:?}", res);
 
-    // Register accounts for all tokens in both MT contracts
-    for account in [alice.clone(), bob.clone(), contract_account.clone()].iter() {
-        // Register for both tokens in the main MT contract
-        res = register_account(account, &mt_contract, "1").await?;
-        assert!(res.is_success(), "Account registration failed {:?}", res);
-        res = register_account(account, &mt_contract, "2").await?;
-        assert!(res.is_success(), "Account registration failed {:?}", res);
-
-        // Register for the token in the faulty MT contract
-        res = register_account(account, &faulty_mt_contract, "1").await?;
-        assert!(res.is_success(), "Account registration failed {:?}", res);
-
-        // Don't send tokens to the contract account
-        if account.id() == contract_account.id() {
-            continue;
-        }
-
+    for account in [alice.clone(), bob.clone()].iter() {
         // Transfer both tokens in the main MT contract
-        res = transfer_tokens(&mt_admin, &mt_contract, account.id(), "1", "100").await?;
+        res = transfer_tokens(&mt_admin, &mt_contract, account.id(), TOKEN_1, "100").await?;
         assert!(res.is_success(), "Token transfer failed {:?}", res);
-        res = transfer_tokens(&mt_admin, &mt_contract, account.id(), "2", "100").await?;
+        res = transfer_tokens(&mt_admin, &mt_contract, account.id(), TOKEN_2, "100").await?;
         assert!(res.is_success(), "Token transfer failed {:?}", res);
 
         // Transfer the token in the faulty MT contract
-        res = transfer_tokens(&mt_admin, &faulty_mt_contract, account.id(), "1", "100").await?;
+        res = transfer_tokens(&mt_admin, &faulty_mt_contract, account.id(), TOKEN_1, "100").await?;
         assert!(res.is_success(), "Token transfer failed {:?}", res);
     }
 
+    // Only the owner or an admin can change the configuration
+    res = bob
+        .call(contract.id(), "set_gas_config")
+        .args_json(json!({ "callback": Gas::from_tgas(15) }))
+        .transact()
+        .await?;
+    assert!(res.is_failure(), "Gas config update should fail {:?}", res);
+
+    res = contract_account
+        .call(contract.id(), "set_gas_config")
+        .args_json(json!({ "callback": Gas::from_tgas(15) }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Gas config update failed {:?}", res);
+
+    let gas_config: serde_json::Value = contract.view("get_gas_config").await?.json()?;
+    assert_eq!(gas_config["callback"], json!(Gas::from_tgas(15)));
+
+    // Gas below the minimum is rejected
+    res = contract_account
+        .call(contract.id(), "set_gas_config")
+        .args_json(json!({ "callback": Gas::from_tgas(1) }))
+        .transact()
+        .await?;
+    assert!(res.is_failure(), "Gas config update should fail {:?}", res);
+
+    // Only the owner or an admin can list tokens
+    res = register_token(&bob, &contract, TOKEN_1, "0").await?;
+    assert!(res.is_failure(), "Token registration should fail {:?}", res);
+
+    for token_id in [TOKEN_1, TOKEN_2] {
+        res = register_token(&contract_account, &contract, token_id, "5").await?;
+        assert!(res.is_success(), "Token registration failed {:?}", res);
+    }
+
+    let supported_tokens: Vec<serde_json::Value> = contract
+        .view("get_supported_tokens")
+        .args_json(json!({}))
+        .await?
+        .json()?;
+    assert_eq!(supported_tokens.len(), 2);
+
+    // A deposit from an account without storage registered is refunded
+    res = transfer_call_tokens(&mt_admin, &mt_contract, contract.id(), TOKEN_1, "10", "").await?;
+    assert!(res.is_success(), "Token transfer failed {:?}", res);
+
+    let token_1_balance = check_balance(&contract_account, &mt_contract, TOKEN_1).await?;
+    assert_eq!(token_1_balance, "0");
+
+    // Alice and Bob pay for their storage in the deposit contract
+    for account in [alice.clone(), bob.clone()].iter() {
+        res = storage_deposit(account, &contract, NearToken::from_millinear(100)).await?;
+        assert!(res.is_success(), "Storage deposit failed {:?}", res);
+    }
+
+    // A deposit below the token's minimum is refunded
+    res = transfer_call_tokens(&alice, &mt_contract, contract.id(), TOKEN_1, "4", "").await?;
+    assert!(res.is_success(), "Token transfer failed {:?}", res);
+    let alice_token_1_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(alice_token_1_balance, None);
+
     // Alice deposits both tokens from the main mint contract to the deposit contract
-    res = transfer_call_tokens(
-        &alice,
-        &mt_contract,
-        contract.id(),
-        "1",
-        "50",
-        "Random message",
-    )
-    .await?;
+    res = transfer_call_tokens(&alice, &mt_contract, contract.id(), TOKEN_1, "50", "").await?;
     assert!(res.is_success(), "Token deposit failed {:?}", res);
 
-    let token_1_balance = check_balance(&contract_account, &mt_contract, "1").await?;
+    // The deposit is reported as a NEP-297 event
+    let deposit_event = format!(
+        r#"EVENT_JSON:{{"standard":"intents_deposit","version":"1.0.0","event":"deposit","data":{{"account_id":"{}","sender_id":"{}","token_id":"{}","amount":"50"}}}}"#,
+        alice.id(),
+        alice.id(),
+        TOKEN_1
+    );
+    assert!(
+        res.logs().contains(&deposit_event.as_str()),
+        "Deposit event not found in {:?}",
+        res.logs()
+    );
+
+    let token_1_balance = check_balance(&contract_account, &mt_contract, TOKEN_1).await?;
     assert_eq!(token_1_balance, "50");
 
-    let alice_token_1_balance = get_token_balance_for_account(&contract, &alice.id(), "1").await?;
+    let alice_token_1_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
     assert_eq!(alice_token_1_balance, Some("50".to_string()));
 
-    res = transfer_call_tokens(
-        &alice,
-        &mt_contract,
-        contract.id(),
-        "2",
-        "30",
-        "Random message",
-    )
-    .await?;
+    // Malformed token Ids are rejected before anything is withdrawn
+    res = withdraw_token(&contract, &alice, "usdc.near", None).await?;
+    assert!(res.is_failure(), "Withdrawal should fail {:?}", res);
+    res = withdraw_token(&contract, &alice, "nep245:mt.near", None).await?;
+    assert!(res.is_failure(), "Withdrawal should fail {:?}", res);
+
+    let token_contract: String = contract
+        .view("get_token_contract")
+        .args_json(json!({ "token_id": "nep141:usdc.near" }))
+        .await?
+        .json()?;
+    assert_eq!(token_contract, "usdc.near");
+
+    res = transfer_call_tokens(&alice, &mt_contract, contract.id(), TOKEN_2, "30", "").await?;
     assert!(res.is_success(), "Token deposit failed {:?}", res);
 
-    let token_2_balance = check_balance(&contract_account, &mt_contract, "2").await?;
+    let token_2_balance = check_balance(&contract_account, &mt_contract, TOKEN_2).await?;
     assert_eq!(token_2_balance, "30");
 
-    let alice_token_2_balance = get_token_balance_for_account(&contract, &alice.id(), "2").await?;
+    let alice_token_2_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_2).await?;
     assert_eq!(alice_token_2_balance, Some("30".to_string()));
 
     // Check all tokens for Alice in the contract
@@ -157,52 +201,153 @@ async fn test_contract_is_operational() -> Result<(), Box<dyn std::error::Error>
         tokens_value.len()
     );
     assert!(
-        tokens_value.contains(&("1".to_string(), "50".to_string())),
+        tokens_value.contains(&(TOKEN_1.to_string(), "50".to_string())),
         "Expected token 1 with balance 50"
     );
     assert!(
-        tokens_value.contains(&("2".to_string(), "30".to_string())),
+        tokens_value.contains(&(TOKEN_2.to_string(), "30".to_string())),
         "Expected token 2 with balance 30"
     );
 
     // Bob sends 10 tokens of token 1 to the contract
-    res = transfer_call_tokens(
-        &bob,
-        &mt_contract,
-        contract.id(),
-        "1",
-        "10",
-        "Random message",
-    )
-    .await?;
+    res = transfer_call_tokens(&bob, &mt_contract, contract.id(), TOKEN_1, "10", "").await?;
     assert!(res.is_success(), "Token deposit failed {:?}", res);
 
     // Check updated contract balance for token 1
-    let token_1_balance = check_balance(&contract_account, &mt_contract, "1").await?;
+    let token_1_balance = check_balance(&contract_account, &mt_contract, TOKEN_1).await?;
     assert_eq!(token_1_balance, "60");
 
     // Check Bob's tokens in the contract
-    let bob_token_1_balance = get_token_balance_for_account(&contract, &bob.id(), "1").await?;
+    let bob_token_1_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_1).await?;
     assert_eq!(bob_token_1_balance, Some("10".to_string()));
 
     // Alice withdraws token 2
-    res = withdraw_token(&contract, &alice, "2").await?;
+    res = withdraw_token(&contract, &alice, TOKEN_2, None).await?;
     assert!(res.is_success(), "Token withdrawal failed {:?}", res);
 
     // Check that contract's balance for token 2 is 0
-    let token_2_balance = check_balance(&contract_account, &mt_contract, "2").await?;
+    let token_2_balance = check_balance(&contract_account, &mt_contract, TOKEN_2).await?;
     assert_eq!(token_2_balance, "0");
 
     // Check that Alice has no token 2
-    let alice_token_2_balance = get_token_balance_for_account(&contract, &alice.id(), "2").await?;
+    let alice_token_2_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_2).await?;
     assert_eq!(alice_token_2_balance, None);
 
+    // Try to bridge a token that isn't a nep141 token
+    res = withdraw_to_chain(
+        &contract,
+        &alice,
+        TOKEN_2,
+        Some("10"),
+        "0x0000000000000000000000000000000000000000",
+    )
+    .await?;
+    assert!(
+        res.is_failure(),
+        "Withdrawal to chain should fail {:?}",
+        res
+    );
+    let alice_token_1_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(alice_token_1_balance, Some("50".to_string()));
+
+    // The MT contract only bridges POA tokens, so the bridge call fails and the balance is restored
+    let address = "0x0000000000000000000000000000000000000001";
+    res = withdraw_to_chain(&contract, &alice, TOKEN_1, Some("10"), address).await?;
+    assert!(res.is_success(), "Withdrawal to chain failed {:?}", res);
+
+    let withdrawal_started_event = format!(
+        r#"EVENT_JSON:{{"standard":"intents_deposit","version":"1.0.0","event":"withdrawal_started","data":{{"nonce":1,"account_id":"{}","receiver_id":"{}","token_id":"{}","amount":"10"}}}}"#,
+        alice.id(),
+        address,
+        TOKEN_1
+    );
+    assert!(
+        res.logs().contains(&withdrawal_started_event.as_str()),
+        "Withdrawal started event not found in {:?}",
+        res.logs()
+    );
+    assert!(
+        res.logs()
+            .iter()
+            .any(|log| log.contains(r#""event":"withdrawal_reverted""#)),
+        "Withdrawal reverted event not found in {:?}",
+        res.logs()
+    );
+    let alice_token_1_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(alice_token_1_balance, Some("50".to_string()));
+    let alice_token_1_pending =
+        get_pending_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(alice_token_1_pending, None);
+
+    // A bridged token is burned by ft_withdraw and the withdrawal completes
+    res = mint_token(&mt_admin, &mt_contract, BRIDGED_TOKEN, 100).await?;
+    assert!(res.is_success(), "Bridged token minting failed {:?}", res);
+    res = transfer_tokens(&mt_admin, &mt_contract, alice.id(), BRIDGED_TOKEN, "100").await?;
+    assert!(res.is_success(), "Token transfer failed {:?}", res);
+    res = register_token(&contract_account, &contract, BRIDGED_TOKEN, "0").await?;
+    assert!(res.is_success(), "Token registration failed {:?}", res);
+    res = transfer_call_tokens(
+        &alice,
+        &mt_contract,
+        contract.id(),
+        BRIDGED_TOKEN,
+        "100",
+        "",
+    )
+    .await?;
+    assert!(res.is_success(), "Token deposit failed {:?}", res);
+
+    res = withdraw_to_chain(&contract, &alice, BRIDGED_TOKEN, Some("40"), address).await?;
+    assert!(res.is_success(), "Withdrawal to chain failed {:?}", res);
+    assert!(
+        res.logs()
+            .iter()
+            .any(|log| log.contains(r#""event":"withdrawal_completed""#)
+                && log.contains(r#""amount":"40""#)),
+        "Withdrawal completed event not found in {:?}",
+        res.logs()
+    );
+
+    // Only the withdrawn amount left the contract's liabilities and its intents balance
+    let liabilities: String = contract
+        .view("get_token_liabilities")
+        .args_json(json!({ "token_id": BRIDGED_TOKEN }))
+        .await?
+        .json()?;
+    assert_eq!(liabilities, "60");
+    let alice_bridged_balance =
+        get_token_balance_for_account(&contract, &alice.id(), BRIDGED_TOKEN).await?;
+    assert_eq!(alice_bridged_balance, Some("60".to_string()));
+    let bridged_balance = check_balance(&contract_account, &mt_contract, BRIDGED_TOKEN).await?;
+    assert_eq!(bridged_balance, "60");
+
     // Try to withdraw token 2 again
-    res = withdraw_token(&contract, &alice, "2").await?;
+    res = withdraw_token(&contract, &alice, TOKEN_2, None).await?;
     assert!(res.is_failure(), "Token withdrawal should fail {:?}", res);
 
-    // Bob withdraws token 1
-    res = withdraw_token(&contract, &bob, "1").await?;
+    // Bob withdraws part of token 1
+    res = withdraw_token(&contract, &bob, TOKEN_1, Some("4")).await?;
+    assert!(
+        res.is_success(),
+        "Partial token withdrawal failed {:?}",
+        res
+    );
+
+    // Check that only the withdrawn amount left the contract
+    let bob_token_1_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_1).await?;
+    assert_eq!(bob_token_1_balance, Some("6".to_string()));
+    let token_1_balance = check_balance(&contract_account, &mt_contract, TOKEN_1).await?;
+    assert_eq!(token_1_balance, "56");
+
+    // Try to withdraw more than the remaining balance
+    res = withdraw_token(&contract, &bob, TOKEN_1, Some("7")).await?;
+    assert!(res.is_failure(), "Token withdrawal should fail {:?}", res);
+
+    // Bob withdraws the rest of token 1
+    res = withdraw_token(&contract, &bob, TOKEN_1, None).await?;
     assert!(res.is_success(), "Token withdrawal failed {:?}", res);
 
     // Check that Bob's token balance array is empty
@@ -214,24 +359,16 @@ async fn test_contract_is_operational() -> Result<(), Box<dyn std::error::Error>
     );
 
     // Try to withdraw token 1 again
-    res = withdraw_token(&contract, &bob, "1").await?;
+    res = withdraw_token(&contract, &bob, TOKEN_1, None).await?;
     assert!(res.is_failure(), "Token withdrawal should fail {:?}", res);
 
     // Try to deposit with the faulty MT token
-    res = transfer_call_tokens(
-        &bob,
-        &faulty_mt_contract,
-        contract.id(),
-        "1",
-        "10",
-        "Random message",
-    )
-    .await?;
+    res = transfer_call_tokens(&bob, &faulty_mt_contract, contract.id(), TOKEN_1, "10", "").await?;
     assert!(res.is_success(), "Token deposit failed {:?}", res);
 
     // Check that the contract's balance for the faulty token is 0
     let contract_faulty_token_balance =
-        get_token_balance_for_account(&contract, &contract_account.id(), "1").await?;
+        get_token_balance_for_account(&contract, &contract_account.id(), TOKEN_1).await?;
     assert_eq!(contract_faulty_token_balance, None);
 
     // Check that the contract's token map for the faulty token is empty
@@ -243,15 +380,7 @@ async fn test_contract_is_operational() -> Result<(), Box<dyn std::error::Error>
     );
 
     // Test balance whilst withdrawal is in progress
-    res = transfer_call_tokens(
-        &alice,
-        &mt_contract,
-        contract.id(),
-        "1",
-        "20",
-        "Random message",
-    )
-    .await?;
+    res = transfer_call_tokens(&alice, &mt_contract, contract.id(), TOKEN_1, "20", "").await?;
     assert!(res.is_success(), "Token deposit failed {:?}", res);
 
     // Spawn the withdrawal operation
@@ -261,27 +390,991 @@ async fn test_contract_is_operational() -> Result<(), Box<dyn std::error::Error>
         alice_clone
             .call(contract_clone.id(), "withdraw_token")
             .args_json(serde_json::json!({
-                "token_id": "1"
+                "token_id": TOKEN_1
             }))
             .gas(Gas::from_tgas(100))
+            .deposit(NearToken::from_yoctonear(1))
             .transact()
             .await
     });
 
-    // Check balance over multiple blocks to check its 0 at some point
-    let mut balance_found_zero = false;
+    // Check over multiple blocks that the balance moves to a pending withdrawal at some point
+    let mut withdrawal_found_pending = false;
     for _ in 0..20 {
         sandbox.fast_forward(1).await?;
-        let balance_during = get_token_balance_for_account(&contract, &alice.id(), "1").await?;
-        if balance_during == Some("0".to_string()) {
-            balance_found_zero = true;
+        let balance_during = get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+        let pending_during =
+            get_pending_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+        if balance_during.is_none() && pending_during == Some("70".to_string()) {
+            withdrawal_found_pending = true;
             break;
         }
     }
     assert!(
-        balance_found_zero,
-        "Balance never reached 0 after 20 blocks"
+        withdrawal_found_pending,
+        "Withdrawal never showed as pending after 20 blocks"
+    );
+
+    // Bob deposits both tokens in a single batch
+    res = batch_transfer_call_tokens(
+        &bob,
+        &mt_contract,
+        contract.id(),
+        &[TOKEN_1, TOKEN_2],
+        &["5", "7"],
+        "",
+    )
+    .await?;
+    assert!(res.is_success(), "Batch token deposit failed {:?}", res);
+
+    let bob_token_1_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_1).await?;
+    assert_eq!(bob_token_1_balance, Some("5".to_string()));
+    let bob_token_2_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_2).await?;
+    assert_eq!(bob_token_2_balance, Some("7".to_string()));
+
+    // Bob withdraws part of both tokens in a single batch
+    res = withdraw_tokens(&contract, &bob, &[(TOKEN_1, "5"), (TOKEN_2, "3")]).await?;
+    assert!(res.is_success(), "Batch token withdrawal failed {:?}", res);
+
+    let bob_token_1_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_1).await?;
+    assert_eq!(bob_token_1_balance, None);
+    let bob_token_2_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_2).await?;
+    assert_eq!(bob_token_2_balance, Some("4".to_string()));
+    let bob_token_2_wallet_balance = check_balance(&bob, &mt_contract, TOKEN_2).await?;
+    assert_eq!(bob_token_2_wallet_balance, "96");
+
+    // Try to withdraw more than the balance in a batch
+    res = withdraw_tokens(&contract, &bob, &[(TOKEN_2, "5")]).await?;
+    assert!(
+        res.is_failure(),
+        "Batch token withdrawal should fail {:?}",
+        res
+    );
+
+    // Bob withdraws the rest of token 2 straight to Alice
+    res = withdraw_token_to(&contract, &bob, TOKEN_2, None, alice.id()).await?;
+    assert!(
+        res.is_success(),
+        "Token withdrawal to receiver failed {:?}",
+        res
+    );
+
+    let bob_token_2_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_2).await?;
+    assert_eq!(bob_token_2_balance, None);
+    let alice_token_2_wallet_balance = check_balance(&alice, &mt_contract, TOKEN_2).await?;
+    assert_eq!(alice_token_2_wallet_balance, "104");
+
+    // Alice deposits token 2 on behalf of Bob
+    res = transfer_call_tokens(
+        &alice,
+        &mt_contract,
+        contract.id(),
+        TOKEN_2,
+        "5",
+        &json!({ "action": "deposit", "beneficiary": bob.id() }).to_string(),
+    )
+    .await?;
+    assert!(res.is_success(), "Beneficiary deposit failed {:?}", res);
+
+    let bob_token_2_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_2).await?;
+    assert_eq!(bob_token_2_balance, Some("5".to_string()));
+    let alice_token_2_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_2).await?;
+    assert_eq!(alice_token_2_balance, None);
+
+    // A deposit with an unknown message is refunded
+    res = transfer_call_tokens(
+        &alice,
+        &mt_contract,
+        contract.id(),
+        TOKEN_2,
+        "10",
+        "Random message",
+    )
+    .await?;
+    assert!(res.is_success(), "Token transfer failed {:?}", res);
+
+    let alice_token_2_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_2).await?;
+    assert_eq!(alice_token_2_balance, None);
+    let alice_token_2_wallet_balance = check_balance(&alice, &mt_contract, TOKEN_2).await?;
+    assert_eq!(alice_token_2_wallet_balance, "99");
+
+    // A NEP-141 deposit of a token that is not registered is refunded
+    let ft_token_id = format!("ft:{}", mt_contract_account.id());
+    let ft_deposit = json!({ "sender_id": alice.id(), "amount": "10", "msg": "" });
+    res = mt_contract_account
+        .call(contract.id(), "ft_on_transfer")
+        .args_json(ft_deposit.clone())
+        .transact()
+        .await?;
+    assert!(res.is_success(), "ft_on_transfer failed {:?}", res);
+    assert_eq!(res.json::<String>()?, "10");
+
+    res = register_token(&contract_account, &contract, &ft_token_id, "0").await?;
+    assert!(res.is_success(), "Token registration failed {:?}", res);
+
+    // Once registered the deposit is credited under the ft: token Id
+    res = mt_contract_account
+        .call(contract.id(), "ft_on_transfer")
+        .args_json(ft_deposit)
+        .transact()
+        .await?;
+    assert!(res.is_success(), "ft_on_transfer failed {:?}", res);
+    assert_eq!(res.json::<String>()?, "0");
+
+    let alice_ft_balance =
+        get_token_balance_for_account(&contract, &alice.id(), &ft_token_id).await?;
+    assert_eq!(alice_ft_balance, Some("10".to_string()));
+
+    // The MT contract has no ft_transfer, so the withdrawal is rolled back
+    res = withdraw_token(&contract, &alice, &ft_token_id, None).await?;
+    assert!(res.is_success(), "Withdraw call failed {:?}", res);
+    let alice_ft_balance =
+        get_token_balance_for_account(&contract, &alice.id(), &ft_token_id).await?;
+    assert_eq!(alice_ft_balance, Some("10".to_string()));
+
+    // An NFT of a collection that is not registered is returned
+    let nft_token_id = format!("nft:{}:nft-1", mt_contract_account.id());
+    let nft_deposit = json!({
+        "sender_id": alice.id(),
+        "previous_owner_id": alice.id(),
+        "token_id": "nft-1",
+        "msg": ""
+    });
+    res = mt_contract_account
+        .call(contract.id(), "nft_on_transfer")
+        .args_json(nft_deposit.clone())
+        .transact()
+        .await?;
+    assert!(res.is_success(), "nft_on_transfer failed {:?}", res);
+    assert!(res.json::<bool>()?, "The NFT should be returned");
+
+    res = contract_account
+        .call(contract.id(), "register_nft_collection")
+        .args_json(json!({
+            "contract_id": mt_contract_account.id(),
+            "wrapped": false,
+            "config": {
+                "enabled": true,
+                "min_deposit": "0",
+                "min_withdrawal": "0",
+                "symbol": "NFT",
+                "decimals": 0,
+                "origin_chain": "near"
+            }
+        }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Collection registration failed {:?}", res);
+
+    // An NFT with a token Id that is too long is returned
+    res = mt_contract_account
+        .call(contract.id(), "nft_on_transfer")
+        .args_json(json!({
+            "sender_id": alice.id(),
+            "previous_owner_id": alice.id(),
+            "token_id": "n".repeat(129),
+            "msg": ""
+        }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "nft_on_transfer failed {:?}", res);
+    assert!(res.json::<bool>()?, "The NFT should be returned");
+
+    // Once registered an NFT sent with nft_transfer_call is locked for its previous owner
+    res = mt_contract_account
+        .call(contract.id(), "nft_on_transfer")
+        .args_json(nft_deposit)
+        .transact()
+        .await?;
+    assert!(res.is_success(), "nft_on_transfer failed {:?}", res);
+    assert!(!res.json::<bool>()?, "The NFT should not be returned");
+
+    let alice_nfts: Vec<String> = contract
+        .view("get_nfts_for_account")
+        .args_json(json!({ "account": alice.id() }))
+        .await?
+        .json()?;
+    assert_eq!(alice_nfts, vec![nft_token_id.clone()]);
+
+    // Bob does not own the NFT
+    res = bob
+        .call(contract.id(), "withdraw_nft")
+        .args_json(json!({ "token_id": nft_token_id }))
+        .gas(Gas::from_tgas(100))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_failure(), "NFT withdrawal should fail {:?}", res);
+
+    // The MT contract has no nft_transfer, so the NFT is locked again
+    res = alice
+        .call(contract.id(), "withdraw_nft")
+        .args_json(json!({ "token_id": nft_token_id }))
+        .gas(Gas::from_tgas(100))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "NFT withdrawal call failed {:?}", res);
+
+    let alice_nfts: Vec<String> = contract
+        .view("get_nfts_for_account")
+        .args_json(json!({ "account": alice.id() }))
+        .await?
+        .json()?;
+    assert_eq!(alice_nfts, vec![nft_token_id]);
+
+    // A deposit over the per-account cap is only accepted up to the cap
+    let alice_token_1_balance: u128 =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1)
+            .await?
+            .unwrap()
+            .parse()?;
+    let alice_token_1_wallet_balance: u128 = check_balance(&alice, &mt_contract, TOKEN_1)
+        .await?
+        .parse()?;
+    res = contract_account
+        .call(contract.id(), "set_token_caps")
+        .args_json(json!({
+            "token_id": TOKEN_1,
+            "account_cap": (alice_token_1_balance + 5).to_string()
+        }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Setting token caps failed {:?}", res);
+
+    res = transfer_call_tokens(&alice, &mt_contract, contract.id(), TOKEN_1, "10", "").await?;
+    assert!(res.is_success(), "Token deposit failed {:?}", res);
+
+    let alice_token_1_capped_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(
+        alice_token_1_capped_balance,
+        Some((alice_token_1_balance + 5).to_string())
+    );
+    let alice_token_1_capped_wallet_balance = check_balance(&alice, &mt_contract, TOKEN_1).await?;
+    assert_eq!(
+        alice_token_1_capped_wallet_balance,
+        (alice_token_1_wallet_balance - 5).to_string()
+    );
+
+    // A deposit is refunded when what fits under the cap is below the minimum deposit
+    res = contract_account
+        .call(contract.id(), "set_token_caps")
+        .args_json(json!({
+            "token_id": TOKEN_1,
+            "account_cap": (alice_token_1_balance + 8).to_string()
+        }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Setting token caps failed {:?}", res);
+
+    res = transfer_call_tokens(&alice, &mt_contract, contract.id(), TOKEN_1, "10", "").await?;
+    assert!(res.is_success(), "Token deposit failed {:?}", res);
+
+    let alice_token_1_capped_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(
+        alice_token_1_capped_balance,
+        Some((alice_token_1_balance + 5).to_string())
+    );
+    let alice_token_1_capped_wallet_balance = check_balance(&alice, &mt_contract, TOKEN_1).await?;
+    assert_eq!(
+        alice_token_1_capped_wallet_balance,
+        (alice_token_1_wallet_balance - 5).to_string()
+    );
+
+    res = contract_account
+        .call(contract.id(), "set_token_caps")
+        .args_json(json!({ "token_id": TOKEN_1 }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Removing token caps failed {:?}", res);
+
+    // The contract holds exactly what it owes for each token
+    res = bob
+        .call(contract.id(), "reconcile_tokens")
+        .args_json(json!({ "token_ids": [TOKEN_1, TOKEN_2] }))
+        .gas(Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Reconciliation failed {:?}", res);
+
+    let reconciliation: Vec<serde_json::Value> = res.json()?;
+    for (token_id, token) in [TOKEN_1, TOKEN_2].iter().zip(reconciliation.iter()) {
+        let held = check_balance(&contract_account, &mt_contract, token_id).await?;
+        assert_eq!(token["held"], json!(held));
+        assert_eq!(token["liabilities"], json!(held));
+        assert_eq!(token["surplus"], json!("0"));
+        assert_eq!(token["deficit"], json!("0"));
+    }
+
+    // Tokens sent without mt_transfer_call are surplus that only the owner can rescue
+    res = transfer_tokens(&bob, &mt_contract, contract.id(), TOKEN_2, "3").await?;
+    assert!(res.is_success(), "Token transfer failed {:?}", res);
+    let bob_token_2_wallet_balance: u128 =
+        check_balance(&bob, &mt_contract, TOKEN_2).await?.parse()?;
+
+    let rescue = json!({ "token_id": TOKEN_2, "receiver_id": bob.id() });
+    res = bob
+        .call(contract.id(), "rescue_surplus")
+        .args_json(rescue.clone())
+        .gas(Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(res.is_failure(), "Rescue should fail {:?}", res);
+
+    res = contract_account
+        .call(contract.id(), "rescue_surplus")
+        .args_json(json!({ "token_id": TOKEN_2, "receiver_id": bob.id(), "amount": "4" }))
+        .gas(Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(
+        res.is_failure(),
+        "Rescuing user funds should fail {:?}",
+        res
     );
 
+    res = contract_account
+        .call(contract.id(), "rescue_surplus")
+        .args_json(rescue)
+        .gas(Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Rescue failed {:?}", res);
+    let bob_token_2_rescued_balance = check_balance(&bob, &mt_contract, TOKEN_2).await?;
+    assert_eq!(
+        bob_token_2_rescued_balance,
+        (bob_token_2_wallet_balance + 3).to_string()
+    );
+
+    // Bob moves part of his token 2 to Alice without leaving the contract
+    res = bob
+        .call(contract.id(), "transfer")
+        .args_json(json!({ "receiver_id": alice.id(), "token_id": TOKEN_2, "amount": "2" }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Transfer failed {:?}", res);
+
+    let bob_token_2_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_2).await?;
+    assert_eq!(bob_token_2_balance, Some("3".to_string()));
+    let alice_token_2_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_2).await?;
+    assert_eq!(alice_token_2_balance, Some("2".to_string()));
+
+    // Alice has no contract to receive a transfer_call, so the amount comes back to Bob
+    res = bob
+        .call(contract.id(), "transfer_call")
+        .args_json(json!({
+            "receiver_id": alice.id(),
+            "token_id": TOKEN_2,
+            "amount": "1",
+            "msg": ""
+        }))
+        .gas(Gas::from_tgas(100))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Transfer call failed {:?}", res);
+    assert_eq!(res.json::<Vec<String>>()?, vec!["0"]);
+
+    let bob_token_2_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_2).await?;
+    assert_eq!(bob_token_2_balance, Some("3".to_string()));
+    let alice_token_2_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_2).await?;
+    assert_eq!(alice_token_2_balance, Some("2".to_string()));
+
+    // Locked balances are NEP-245 receipts that move with mt_transfer and 1 yoctoNEAR
+    res = alice
+        .call(contract.id(), "mt_transfer")
+        .args_json(json!({ "receiver_id": bob.id(), "token_id": TOKEN_2, "amount": "1" }))
+        .transact()
+        .await?;
+    assert!(
+        res.is_failure(),
+        "mt_transfer without 1 yoctoNEAR should fail"
+    );
+
+    res = alice
+        .call(contract.id(), "mt_transfer")
+        .args_json(json!({ "receiver_id": bob.id(), "token_id": TOKEN_2, "amount": "1" }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "mt_transfer failed {:?}", res);
+
+    let bob_receipts: String = contract
+        .view("mt_balance_of")
+        .args_json(json!({ "account_id": bob.id(), "token_id": TOKEN_2 }))
+        .await?
+        .json()?;
+    assert_eq!(bob_receipts, "4");
+
+    res = bob
+        .call(contract.id(), "mt_transfer")
+        .args_json(json!({ "receiver_id": alice.id(), "token_id": TOKEN_2, "amount": "1" }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "mt_transfer failed {:?}", res);
+
+    let receipts: Vec<String> = contract
+        .view("mt_batch_balance_of")
+        .args_json(json!({ "account_id": alice.id(), "token_ids": [TOKEN_1, TOKEN_2] }))
+        .await?
+        .json()?;
+    assert_eq!(receipts[1], "2");
+
+    // Carol pays a receiver contract that keeps what it gets unless msg asks for a refund
+    let carol = create_subaccount(&root, "carol").await?;
+    let receiver_account = create_subaccount(&root, "receiver").await?;
+    let receiver = receiver_account.deploy(&mt_wasm).await?.unwrap();
+    res = receiver.call("new").transact().await?;
+    assert!(res.is_success(), "Receiver initialization failed {:?}", res);
+
+    res = transfer_tokens(&mt_admin, &mt_contract, carol.id(), TOKEN_2, "100").await?;
+    assert!(res.is_success(), "Token transfer failed {:?}", res);
+    res = storage_deposit(&carol, &contract, NearToken::from_millinear(100)).await?;
+    assert!(res.is_success(), "Storage deposit failed {:?}", res);
+    res = carol
+        .call(contract.id(), "storage_deposit")
+        .args_json(json!({ "account_id": receiver.id() }))
+        .deposit(NearToken::from_millinear(100))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Storage deposit failed {:?}", res);
+    res = transfer_call_tokens(&carol, &mt_contract, contract.id(), TOKEN_2, "10", "").await?;
+    assert!(res.is_success(), "Token deposit failed {:?}", res);
+
+    // The receiver keeps the whole transfer
+    res = carol
+        .call(contract.id(), "transfer_call")
+        .args_json(json!({
+            "receiver_id": receiver.id(),
+            "token_id": TOKEN_2,
+            "amount": "3",
+            "msg": ""
+        }))
+        .gas(Gas::from_tgas(100))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Transfer call failed {:?}", res);
+    assert_eq!(res.json::<Vec<String>>()?, vec!["3"]);
+
+    // The receiver refunds part of the transfer, which goes back to Carol
+    res = carol
+        .call(contract.id(), "transfer_call")
+        .args_json(json!({
+            "receiver_id": receiver.id(),
+            "token_id": TOKEN_2,
+            "amount": "3",
+            "msg": "1"
+        }))
+        .gas(Gas::from_tgas(100))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Transfer call failed {:?}", res);
+    assert_eq!(res.json::<Vec<String>>()?, vec!["2"]);
+
+    let carol_token_2_balance =
+        get_token_balance_for_account(&contract, &carol.id(), TOKEN_2).await?;
+    assert_eq!(carol_token_2_balance, Some("5".to_string()));
+    let receiver_token_2_balance =
+        get_token_balance_for_account(&contract, &receiver.id(), TOKEN_2).await?;
+    assert_eq!(receiver_token_2_balance, Some("5".to_string()));
+
+    // Receipts sent with mt_transfer_call are resolved the same way
+    res = carol
+        .call(contract.id(), "mt_transfer_call")
+        .args_json(json!({
+            "receiver_id": receiver.id(),
+            "token_id": TOKEN_2,
+            "amount": "2",
+            "msg": ""
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .gas(Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "mt_transfer_call failed {:?}", res);
+    assert_eq!(res.json::<Vec<String>>()?, vec!["2".to_string()]);
+
+    res = carol
+        .call(contract.id(), "mt_transfer_call")
+        .args_json(json!({
+            "receiver_id": receiver.id(),
+            "token_id": TOKEN_2,
+            "amount": "3",
+            "msg": "2"
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .gas(Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "mt_transfer_call failed {:?}", res);
+    assert_eq!(res.json::<Vec<String>>()?, vec!["1".to_string()]);
+
+    let carol_receipts: String = contract
+        .view("mt_balance_of")
+        .args_json(json!({ "account_id": carol.id(), "token_id": TOKEN_2 }))
+        .await?
+        .json()?;
+    assert_eq!(carol_receipts, "2");
+    let receiver_receipts: String = contract
+        .view("mt_balance_of")
+        .args_json(json!({ "account_id": receiver.id(), "token_id": TOKEN_2 }))
+        .await?
+        .json()?;
+    assert_eq!(receiver_receipts, "8");
+
+    // Bob cannot lock a deposit for Alice
+    let lock_for_alice = json!({
+        "action": "deposit",
+        "beneficiary": alice.id(),
+        "lock_duration": "86400000000000"
+    })
+    .to_string();
+    res = transfer_call_tokens(
+        &bob,
+        &mt_contract,
+        contract.id(),
+        TOKEN_1,
+        "10",
+        &lock_for_alice,
+    )
+    .await?;
+    assert!(res.is_success(), "Token transfer failed {:?}", res);
+
+    let locked_balance: serde_json::Value = contract
+        .view("get_locked_balance")
+        .args_json(json!({ "account_id": alice.id(), "token_id": TOKEN_1 }))
+        .await?
+        .json()?;
+    assert_eq!(locked_balance["locked"], "0");
+
+    // Alice locks a deposit for a day, only the rest of her balance can leave the contract
+    let lock_msg = json!({ "action": "deposit", "lock_duration": "86400000000000" }).to_string();
+    res = transfer_call_tokens(
+        &alice,
+        &mt_contract,
+        contract.id(),
+        TOKEN_1,
+        "10",
+        &lock_msg,
+    )
+    .await?;
+    assert!(res.is_success(), "Token deposit failed {:?}", res);
+
+    let locked_balance: serde_json::Value = contract
+        .view("get_locked_balance")
+        .args_json(json!({ "account_id": alice.id(), "token_id": TOKEN_1 }))
+        .await?
+        .json()?;
+    assert_eq!(locked_balance["locked"], "10");
+    assert_ne!(locked_balance["unlocked"], "0");
+
+    res = withdraw_token(&contract, &alice, TOKEN_1, None).await?;
+    assert!(res.is_success(), "Token withdrawal failed {:?}", res);
+    let alice_token_1_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(alice_token_1_balance, Some("10".to_string()));
+
+    res = withdraw_token(&contract, &alice, TOKEN_1, Some("10")).await?;
+    assert!(
+        res.is_failure(),
+        "Withdrawing a locked lot should fail {:?}",
+        res
+    );
+    res = alice
+        .call(contract.id(), "transfer")
+        .args_json(json!({ "receiver_id": bob.id(), "token_id": TOKEN_1, "amount": "10" }))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?;
+    assert!(
+        res.is_failure(),
+        "Transferring a locked lot should fail {:?}",
+        res
+    );
+
+    // Bob vests part of a deposit to Alice on a schedule that has already ended
+    res = transfer_call_tokens(&bob, &mt_contract, contract.id(), TOKEN_1, "20", "").await?;
+    assert!(res.is_success(), "Token deposit failed {:?}", res);
+
+    res = bob
+        .call(contract.id(), "create_vesting")
+        .args_json(json!({
+            "beneficiary_id": alice.id(),
+            "token_id": TOKEN_1,
+            "amount": "10",
+            "start": "0",
+            "cliff": "0",
+            "end": "1",
+            "revocable": false
+        }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Creating the vesting failed {:?}", res);
+    let schedule_id: u64 = res.json()?;
+
+    res = bob
+        .call(contract.id(), "claim_vested")
+        .args_json(json!({ "schedule_id": schedule_id }))
+        .gas(Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(
+        res.is_failure(),
+        "Only the beneficiary should claim {:?}",
+        res
+    );
+
+    let alice_token_1_wallet_balance: u128 = check_balance(&alice, &mt_contract, TOKEN_1)
+        .await?
+        .parse()?;
+    res = alice
+        .call(contract.id(), "claim_vested")
+        .args_json(json!({ "schedule_id": schedule_id }))
+        .gas(Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Claiming failed {:?}", res);
+    let alice_token_1_claimed_wallet_balance = check_balance(&alice, &mt_contract, TOKEN_1).await?;
+    assert_eq!(
+        alice_token_1_claimed_wallet_balance,
+        (alice_token_1_wallet_balance + 10).to_string()
+    );
+
+    // A revocable schedule that has not started returns everything to Bob
+    res = bob
+        .call(contract.id(), "create_vesting")
+        .args_json(json!({
+            "beneficiary_id": alice.id(),
+            "token_id": TOKEN_1,
+            "amount": "10",
+            "start": "9000000000000000000",
+            "cliff": "9000000000000000000",
+            "end": "9000000000000000001",
+            "revocable": true
+        }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Creating the vesting failed {:?}", res);
+    let schedule_id: u64 = res.json()?;
+
+    res = bob
+        .call(contract.id(), "revoke_vesting")
+        .args_json(json!({ "schedule_id": schedule_id }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Revoking failed {:?}", res);
+    assert_eq!(res.json::<String>()?, "10");
+
+    // Holders of token 1 earn rewards in token 2, paid out over an hour
+    res = contract_account
+        .call(contract.id(), "create_reward_pool")
+        .args_json(json!({
+            "staked_token_id": TOKEN_1,
+            "reward_token_id": TOKEN_2,
+            "duration": "3600000000000"
+        }))
+        .transact()
+        .await?;
+    assert!(
+        res.is_success(),
+        "Creating the reward pool failed {:?}",
+        res
+    );
+
+    res = transfer_call_tokens(&bob, &mt_contract, contract.id(), TOKEN_1, "10", "").await?;
+    assert!(res.is_success(), "Token deposit failed {:?}", res);
+
+    // Alice held token 1, locked lot included, before the pool existed and has to be synced.
+    // Holders can only sync their own stake, the owner syncs both before funding the pool
+    res = bob
+        .call(contract.id(), "sync_reward_stakes")
+        .args_json(json!({ "staked_token_id": TOKEN_1, "account_ids": [alice.id()] }))
+        .transact()
+        .await?;
+    assert!(
+        res.is_failure(),
+        "Syncing another stake should fail {:?}",
+        res
+    );
+    res = contract_account
+        .call(contract.id(), "sync_reward_stakes")
+        .args_json(json!({ "staked_token_id": TOKEN_1, "account_ids": [alice.id(), bob.id()] }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Syncing stakes failed {:?}", res);
+
+    let alice_staked: u128 = get_token_balance_for_account(&contract, &alice.id(), TOKEN_1)
+        .await?
+        .unwrap()
+        .parse()?;
+    let bob_staked: u128 = get_token_balance_for_account(&contract, &bob.id(), TOKEN_1)
+        .await?
+        .unwrap()
+        .parse()?;
+    let pool: serde_json::Value = contract
+        .view("get_reward_pool")
+        .args_json(json!({ "staked_token_id": TOKEN_1 }))
+        .await?
+        .json()?;
+    assert_eq!(
+        pool["total_staked"],
+        (alice_staked + bob_staked).to_string()
+    );
+
+    // Only reward funders can fund rewards, other transfers are refunded
+    let fund_msg = json!({ "action": "fund_rewards", "staked_token_id": TOKEN_1 }).to_string();
+    let alice_token_2_wallet_balance = check_balance(&alice, &mt_contract, TOKEN_2).await?;
+    res = transfer_call_tokens(
+        &alice,
+        &mt_contract,
+        contract.id(),
+        TOKEN_2,
+        "100",
+        &fund_msg,
+    )
+    .await?;
+    assert!(res.is_success(), "Token transfer failed {:?}", res);
+    assert_eq!(
+        check_balance(&alice, &mt_contract, TOKEN_2).await?,
+        alice_token_2_wallet_balance
+    );
+
+    res = contract_account
+        .call(contract.id(), "grant_role")
+        .args_json(json!({ "account_id": mt_admin.id(), "role": "RewardFunder" }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Granting the role failed {:?}", res);
+    res = transfer_call_tokens(
+        &mt_admin,
+        &mt_contract,
+        contract.id(),
+        TOKEN_2,
+        "100",
+        &fund_msg,
+    )
+    .await?;
+    assert!(res.is_success(), "Funding rewards failed {:?}", res);
+
+    // Nothing touches the pool until the whole period has passed
+    let pool: serde_json::Value = contract
+        .view("get_reward_pool")
+        .args_json(json!({ "staked_token_id": TOKEN_1 }))
+        .await?
+        .json()?;
+    let period_end: u64 = pool["period_end"].as_str().unwrap().parse()?;
+    while sandbox.view_block().await?.timestamp() < period_end {
+        sandbox.fast_forward(100).await?;
+    }
+
+    // Alice and Bob share the rewards in proportion to their balances, rounded down
+    let reward_per_share = 100 * 10u128.pow(18) / (alice_staked + bob_staked);
+    for (account, staked) in [(&alice, alice_staked), (&bob, bob_staked)] {
+        let unclaimed_rewards: String = contract
+            .view("get_unclaimed_rewards")
+            .args_json(json!({ "account_id": account.id(), "staked_token_id": TOKEN_1 }))
+            .await?
+            .json()?;
+        assert_eq!(
+            unclaimed_rewards,
+            (staked * reward_per_share / 10u128.pow(18)).to_string()
+        );
+    }
+    let expected_rewards = bob_staked * reward_per_share / 10u128.pow(18);
+
+    let bob_token_2_wallet_balance: u128 =
+        check_balance(&bob, &mt_contract, TOKEN_2).await?.parse()?;
+    res = bob
+        .call(contract.id(), "claim_rewards")
+        .args_json(json!({ "staked_token_id": TOKEN_1 }))
+        .gas(Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Claiming rewards failed {:?}", res);
+    assert_eq!(
+        check_balance(&bob, &mt_contract, TOKEN_2).await?,
+        (bob_token_2_wallet_balance + expected_rewards).to_string()
+    );
+
+    // Only the owner can upgrade the contract
+    res = bob
+        .call(contract.id(), "upgrade")
+        .args(contract_wasm.clone())
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(res.is_failure(), "Contract upgrade should fail {:?}", res);
+
+    // The owner upgrades the contract while it holds live balances
+    res = contract_account
+        .call(contract.id(), "upgrade")
+        .args(contract_wasm.clone())
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Contract upgrade failed {:?}", res);
+
+    let state_version: String = contract.view("get_state_version").await?.json()?;
+    assert_eq!(state_version, "V2");
+    let bob_token_2_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_2).await?;
+    assert_eq!(bob_token_2_balance, Some("3".to_string()));
+    let pending_withdrawal: Option<serde_json::Value> = contract
+        .view("get_pending_withdrawal")
+        .args_json(json!({ "nonce": 0 }))
+        .await?
+        .json()?;
+    assert!(pending_withdrawal.is_none());
+
+    res = contract_account
+        .call(contract.id(), "set_paused")
+        .args_json(json!({ "deposits": true }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Pausing deposits failed {:?}", res);
+
+    res = transfer_call_tokens(&alice, &mt_contract, contract.id(), TOKEN_2, "10", "").await?;
+    assert!(res.is_success(), "Token transfer failed {:?}", res);
+    let alice_token_2_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_2).await?;
+    assert_eq!(alice_token_2_balance, Some("2".to_string()));
+
+    // In emergency mode tokens can only be withdrawn back to their owner
+    res = contract_account
+        .call(contract.id(), "set_emergency_mode")
+        .args_json(json!({ "enabled": true }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Enabling emergency mode failed {:?}", res);
+
+    res = withdraw_token_to(&contract, &bob, TOKEN_2, None, alice.id()).await?;
+    assert!(res.is_failure(), "Token withdrawal should fail {:?}", res);
+
+    res = withdraw_token(&contract, &bob, TOKEN_2, None).await?;
+    assert!(res.is_success(), "Token withdrawal failed {:?}", res);
+    let bob_token_2_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_2).await?;
+    assert_eq!(bob_token_2_balance, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upgrade_from_first_release() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+    let root: near_workspaces::Account = sandbox.root_account()?;
+
+    let alice = create_subaccount(&root, "alice").await?;
+    let bob = create_subaccount(&root, "bob").await?;
+    let contract_account = create_subaccount(&root, "contract").await?;
+    let mt_contract_account = create_subaccount(&root, "mt").await?;
+    let mt_admin = create_subaccount(&root, "mt_admin").await?;
+
+    // Deploy the first release of the deposit contract
+    let v1_wasm = near_workspaces::compile_project(V1_CONTRACT_PATH).await?;
+    let contract = contract_account.deploy(&v1_wasm).await?.unwrap();
+    let mut res = contract
+        .call("new")
+        .args_json(json!({"intents_contract_id": mt_contract_account.id() }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Contract initialization failed {:?}", res);
+
+    let mt_wasm = near_workspaces::compile_project(MOCK_INTENTS_PATH).await?;
+    let mt_contract = mt_contract_account.deploy(&mt_wasm).await?.unwrap();
+    res = mt_contract.call("new").transact().await?;
+    assert!(
+        res.is_success(),
+        "MT contract initialization failed {:?}",
+        res
+    );
+    res = mint_token(&mt_admin, &mt_contract, TOKEN_1, 1000).await?;
+    assert!(res.is_success(), "Token minting failed {:?}", res);
+
+    // Alice and Bob deposit into the first release
+    for (account, amount) in [(&alice, "50"), (&bob, "30")] {
+        res = transfer_tokens(&mt_admin, &mt_contract, account.id(), TOKEN_1, "100").await?;
+        assert!(res.is_success(), "Token transfer failed {:?}", res);
+        res =
+            transfer_call_tokens(account, &mt_contract, contract.id(), TOKEN_1, amount, "").await?;
+        assert!(res.is_success(), "Token deposit failed {:?}", res);
+    }
+    let alice_token_1_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(alice_token_1_balance, Some("50".to_string()));
+
+    // The first release has no upgrade method, so the account deploys the new code itself
+    let contract_wasm = near_workspaces::compile_project("./").await?;
+    let contract = contract_account.deploy(&contract_wasm).await?.unwrap();
+    res = contract_account
+        .call(contract.id(), "migrate")
+        .args_json(json!({}))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Contract migration failed {:?}", res);
+
+    let state_version: String = contract.view("get_state_version").await?.json()?;
+    assert_eq!(state_version, "V2");
+    let owner_id: Option<String> = contract.view("own_get_owner").await?.json()?;
+    assert_eq!(owner_id, Some(contract_account.id().to_string()));
+    let alice_token_1_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(alice_token_1_balance, Some("50".to_string()));
+    let bob_token_1_balance = get_token_balance_for_account(&contract, &bob.id(), TOKEN_1).await?;
+    assert_eq!(bob_token_1_balance, Some("30".to_string()));
+
+    // Surplus can't be rescued until every account's balances are in the token totals
+    let totals_complete: bool = contract.view("get_totals_complete").await?.json()?;
+    assert!(!totals_complete);
+    res = contract_account
+        .call(contract.id(), "rescue_surplus")
+        .args_json(json!({ "token_id": TOKEN_1, "receiver_id": contract_account.id() }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(res.is_failure(), "Rescuing surplus should fail {:?}", res);
+
+    res = contract_account
+        .call(contract.id(), "migrate_account_balances")
+        .args_json(json!({ "account_ids": [alice.id(), bob.id()] }))
+        .transact()
+        .await?;
+    assert!(res.is_success(), "Migrating accounts failed {:?}", res);
+    res = contract_account
+        .call(contract.id(), "complete_account_migration")
+        .transact()
+        .await?;
+    assert!(
+        res.is_success(),
+        "Completing the migration failed {:?}",
+        res
+    );
+
+    let liabilities: String = contract
+        .view("get_token_liabilities")
+        .args_json(json!({ "token_id": TOKEN_1 }))
+        .await?
+        .json()?;
+    assert_eq!(liabilities, "80");
+    let totals_complete: bool = contract.view("get_totals_complete").await?.json()?;
+    assert!(totals_complete);
+
+    // Balances deposited into the first release can be withdrawn after the upgrade
+    res = withdraw_token(&contract, &alice, TOKEN_1, Some("20")).await?;
+    assert!(res.is_success(), "Token withdrawal failed {:?}", res);
+    let alice_token_1_balance =
+        get_token_balance_for_account(&contract, &alice.id(), TOKEN_1).await?;
+    assert_eq!(alice_token_1_balance, Some("30".to_string()));
+    assert_eq!(check_balance(&alice, &mt_contract, TOKEN_1).await?, "70");
+
     Ok(())
 }
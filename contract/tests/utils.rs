@@ -1,27 +1,9 @@
-use near_sdk::near;
 use near_sdk_v4::Balance;
 use near_workspaces::result::ExecutionFinalResult;
 use near_workspaces::types::{AccountId, Gas, NearToken};
-use serde::Serialize;
 
 const TEN_NEAR: NearToken = NearToken::from_near(10);
 
-#[near]
-#[derive(Serialize)]
-pub struct TokenMetadata {
-    pub title: Option<String>,
-    pub description: Option<String>,
-    pub media: Option<String>,
-    pub media_hash: Option<String>,
-    pub issued_at: Option<String>,
-    pub expires_at: Option<String>,
-    pub starts_at: Option<String>,
-    pub updated_at: Option<String>,
-    pub extra: Option<String>,
-    pub reference: Option<String>,
-    pub reference_hash: Option<String>,
-}
-
 pub async fn create_subaccount(
     root: &near_workspaces::Account,
     name: &str,
@@ -39,31 +21,16 @@ pub async fn create_subaccount(
 pub async fn mint_token(
     mt_admin: &near_workspaces::Account,
     mt_contract: &near_workspaces::Contract,
-    title: &str,
+    token_id: &str,
     supply: Balance,
 ) -> Result<ExecutionFinalResult, Box<dyn std::error::Error>> {
-    let token_metadata = TokenMetadata {
-        title: Some(title.to_string()),
-        description: None,
-        media: None,
-        media_hash: None,
-        issued_at: None,
-        expires_at: None,
-        starts_at: None,
-        updated_at: None,
-        extra: None,
-        reference: None,
-        reference_hash: None,
-    };
-
     Ok(mt_admin
         .call(mt_contract.id(), "mt_mint")
         .args_json(serde_json::json!({
-            "token_owner_id": mt_admin.id(),
-            "token_metadata": token_metadata,
-            "supply": supply
+            "owner_id": mt_admin.id(),
+            "token_id": token_id,
+            "amount": supply.to_string()
         }))
-        .deposit(NearToken::from_near(1))
         .transact()
         .await?)
 }
@@ -85,16 +52,37 @@ pub async fn check_balance(
     Ok(balance_value)
 }
 
-pub async fn register_account(
+pub async fn storage_deposit(
     account: &near_workspaces::Account,
-    mt_contract: &near_workspaces::Contract,
-    token_id: &str,
+    contract: &near_workspaces::Contract,
+    deposit: NearToken,
 ) -> Result<ExecutionFinalResult, Box<dyn std::error::Error>> {
     Ok(account
-        .call(mt_contract.id(), "register")
+        .call(contract.id(), "storage_deposit")
+        .args_json(serde_json::json!({}))
+        .deposit(deposit)
+        .transact()
+        .await?)
+}
+
+pub async fn register_token(
+    admin: &near_workspaces::Account,
+    contract: &near_workspaces::Contract,
+    token_id: &str,
+    min_deposit: &str,
+) -> Result<ExecutionFinalResult, Box<dyn std::error::Error>> {
+    Ok(admin
+        .call(contract.id(), "register_token")
         .args_json(serde_json::json!({
             "token_id": token_id,
-            "account_id": account.id()
+            "config": {
+                "enabled": true,
+                "min_deposit": min_deposit,
+                "min_withdrawal": "0",
+                "symbol": "TKN",
+                "decimals": 0,
+                "origin_chain": "near"
+            }
         }))
         .transact()
         .await?)
@@ -141,6 +129,28 @@ pub async fn transfer_call_tokens(
         .await?)
 }
 
+pub async fn batch_transfer_call_tokens(
+    sender: &near_workspaces::Account,
+    mt_contract: &near_workspaces::Contract,
+    receiver_id: &AccountId,
+    token_ids: &[&str],
+    amounts: &[&str],
+    msg: &str,
+) -> Result<ExecutionFinalResult, Box<dyn std::error::Error>> {
+    Ok(sender
+        .call(mt_contract.id(), "mt_batch_transfer_call")
+        .args_json(serde_json::json!({
+            "receiver_id": receiver_id,
+            "token_ids": token_ids,
+            "amounts": amounts,
+            "msg": msg
+        }))
+        .deposit(NearToken::from_yoctonear(1))
+        .gas(Gas::from_tgas(150))
+        .transact()
+        .await?)
+}
+
 pub async fn get_tokens_for_account(
     contract: &near_workspaces::Contract,
     target_account: &AccountId,
@@ -162,13 +172,72 @@ pub async fn withdraw_token(
     contract: &near_workspaces::Contract,
     account: &near_workspaces::Account,
     token_id: &str,
+    amount: Option<&str>,
 ) -> Result<ExecutionFinalResult, Box<dyn std::error::Error>> {
     Ok(account
         .call(contract.id(), "withdraw_token")
         .args_json(serde_json::json!({
-            "token_id": token_id
+            "token_id": token_id,
+            "amount": amount
         }))
         .gas(Gas::from_tgas(100))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?)
+}
+
+pub async fn withdraw_token_to(
+    contract: &near_workspaces::Contract,
+    account: &near_workspaces::Account,
+    token_id: &str,
+    amount: Option<&str>,
+    receiver_id: &AccountId,
+) -> Result<ExecutionFinalResult, Box<dyn std::error::Error>> {
+    Ok(account
+        .call(contract.id(), "withdraw_token")
+        .args_json(serde_json::json!({
+            "token_id": token_id,
+            "amount": amount,
+            "receiver_id": receiver_id
+        }))
+        .gas(Gas::from_tgas(100))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?)
+}
+
+pub async fn withdraw_to_chain(
+    contract: &near_workspaces::Contract,
+    account: &near_workspaces::Account,
+    token_id: &str,
+    amount: Option<&str>,
+    address: &str,
+) -> Result<ExecutionFinalResult, Box<dyn std::error::Error>> {
+    Ok(account
+        .call(contract.id(), "withdraw_to_chain")
+        .args_json(serde_json::json!({
+            "token_id": token_id,
+            "amount": amount,
+            "address": address
+        }))
+        .gas(Gas::from_tgas(100))
+        .deposit(NearToken::from_yoctonear(1))
+        .transact()
+        .await?)
+}
+
+pub async fn withdraw_tokens(
+    contract: &near_workspaces::Contract,
+    account: &near_workspaces::Account,
+    tokens: &[(&str, &str)],
+) -> Result<ExecutionFinalResult, Box<dyn std::error::Error>> {
+    Ok(account
+        .call(contract.id(), "withdraw_tokens")
+        .args_json(serde_json::json!({
+            "tokens": tokens
+        }))
+        .gas(Gas::from_tgas(150))
+        .deposit(NearToken::from_yoctonear(1))
         .transact()
         .await?)
 }
@@ -188,3 +257,19 @@ pub async fn get_token_balance_for_account(
     let balance: Option<String> = res.json()?;
     Ok(balance)
 }
+
+pub async fn get_pending_balance_for_account(
+    contract: &near_workspaces::Contract,
+    target_account: &AccountId,
+    token_id: &str,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let res = contract
+        .view("get_pending_balance_for_account")
+        .args_json(serde_json::json!({
+            "account": target_account,
+            "token_id": token_id
+        }))
+        .await?;
+    let balance: Option<String> = res.json()?;
+    Ok(balance)
+}
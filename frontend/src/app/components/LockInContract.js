@@ -29,6 +29,29 @@ export default function LockInContract({ selectedToken }) {
                 return;
             }
 
+            // Make sure the account has paid for its storage in the contract, otherwise the deposit is refunded
+            const storageBalance = await viewFunction({
+                contractId: process.env.NEXT_PUBLIC_CONTRACT_ID,
+                method: 'storage_balance_of',
+                args: {
+                    account_id: signedAccountId
+                }
+            });
+            if (!storageBalance) {
+                const bounds = await viewFunction({
+                    contractId: process.env.NEXT_PUBLIC_CONTRACT_ID,
+                    method: 'storage_balance_bounds',
+                    args: {}
+                });
+                await callFunction({
+                    contractId: process.env.NEXT_PUBLIC_CONTRACT_ID,
+                    method: 'storage_deposit',
+                    args: {},
+                    gas: '30000000000000',
+                    deposit: bounds.min,
+                });
+            }
+
             // Make a call to the intents contract to lock all the funds in the contract
             await callFunction({
                 contractId: 'intents.near',
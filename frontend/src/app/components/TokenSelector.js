@@ -1,10 +1,13 @@
 'use client';
 
 import { useState, useEffect } from 'react';
+import { useWalletSelector } from '@near-wallet-selector/react-hook';
 import { getChainDisplayName } from '../../utils/chainNames';
-import { tokenList } from '../../utils/tokenList';
+
+const SUPPORTED_TOKENS_PAGE_SIZE = 100;
 
 export default function TokenSelector({ onAssetSelect, onChainSelect, onTokensLoaded }) {
+  const { viewFunction } = useWalletSelector();
   const [tokens, setTokens] = useState([]);
   const [isLoading, setIsLoading] = useState(true);
   const [error, setError] = useState(null);
@@ -28,22 +31,26 @@ export default function TokenSelector({ onAssetSelect, onChainSelect, onTokensLo
 
         if (!response.ok) throw new Error('Failed to fetch assets');
         const data = await response.json();
-        
-        // Filter to only have tokens that use the POA bridge
-        const validTokens = data.result.tokens.filter(apiToken => {
-          const token_id = apiToken.intents_token_id; 
-          return tokenList.some(listToken => {
-            if (listToken.defuseAssetId === token_id && listToken.bridge === "poa") {
-              return true;
-            }
-            if (listToken.groupedTokens) {
-              return listToken.groupedTokens.some(groupedToken => 
-                groupedToken.defuseAssetId === token_id && groupedToken.bridge === "poa"
-              );
-            }
-            return false;
+
+        // Get the tokens listed in the contract's registry, a page at a time. Only nep141 tokens
+        // can be bridged back with withdraw_to_chain, so other ones (ft:, nft:, nep245:...) are skipped
+        const enabledTokenIds = new Set();
+        for (let fromIndex = 0; ; fromIndex += SUPPORTED_TOKENS_PAGE_SIZE) {
+          const page = await viewFunction({
+            contractId: process.env.NEXT_PUBLIC_CONTRACT_ID,
+            method: 'get_supported_tokens',
+            args: { from_index: fromIndex, limit: SUPPORTED_TOKENS_PAGE_SIZE }
           });
-        });
+          page
+            .filter(([tokenId, config]) => config.enabled && tokenId.startsWith('nep141:'))
+            .forEach(([tokenId]) => enabledTokenIds.add(tokenId));
+          if (page.length < SUPPORTED_TOKENS_PAGE_SIZE) break;
+        }
+
+        // Filter to only have tokens the contract accepts
+        const validTokens = data.result.tokens.filter(apiToken =>
+          enabledTokenIds.has(apiToken.intents_token_id)
+        );
 
         setTokens(validTokens);
         onTokensLoaded && onTokensLoaded(validTokens);
@@ -66,7 +73,7 @@ export default function TokenSelector({ onAssetSelect, onChainSelect, onTokensLo
       }
     }
     fetchAssets();
-  }, [onAssetSelect, onChainSelect, onTokensLoaded]);
+  }, [onAssetSelect, onChainSelect, onTokensLoaded, viewFunction]);
 
   // Get unique asset names
   const uniqueAssets = [...new Set(tokens.map(token => token.asset_name))];
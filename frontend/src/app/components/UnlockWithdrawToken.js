@@ -5,7 +5,7 @@ import { getChainDisplayName } from '../../utils/chainNames';
 import { useWalletSelector } from '@near-wallet-selector/react-hook';
 
 export default function UnlockWithdrawToken({ selectedToken, onWithdraw }) {
-    const { signedAccountId, wallet, callFunction, viewFunction } = useWalletSelector();
+    const { signedAccountId, wallet } = useWalletSelector();
     const [address, setAddress] = useState("");
     const [loading, setLoading] = useState(false);
     const [error, setError] = useState(null);
@@ -20,94 +20,25 @@ export default function UnlockWithdrawToken({ selectedToken, onWithdraw }) {
         ? selectedToken.defuse_asset_identifier.split(":").slice(0, 2).join(":")
         : "";
 
-    const handleUnlock = async () => {
-        if (!selectedToken) {
-            setError('Please select a token to unlock');
-            return;
-        }
-        setLoading(true);
-        setError(null);
-        setUnlockSuccess(false);
-
-        try {
-            // Make a call to the contract to unlock the token
-            await callFunction({
-                contractId: process.env.NEXT_PUBLIC_CONTRACT_ID,
-                method: 'withdraw_token',
-                args: {
-                    token_id: selectedToken.intents_token_id,
-                },
-                gas: '100000000000000',
-            });
-            setUnlockSuccess(true);
-            return true;
-        } catch (err) {
-            setError(err.message || 'Unlock transaction failed');
-            console.error('Unlock error:', err);
-            return false;
-        } finally {
-            setLoading(false);
-        }
-    };
-
-    const getDepositBalance = async () => {
-        try {
-            // Make a view call to the intents contract to get the deposit balance
-            const balance = await viewFunction({
-                contractId: 'intents.near',
-                method: 'mt_balance_of',
-                args: {
-                  account_id: signedAccountId,
-                  token_id: selectedToken.intents_token_id
-                }
-              });
-            return balance;
-        } catch (err) {
-            console.error('Error fetching contract balance:', err);
-            throw new Error('Failed to fetch contract balance');
-        }
-    };
-
     async function handleSubmit(e) {
         e.preventDefault();
-        if (!selectedToken || !signedAccountId) return;  
+        if (!selectedToken || !signedAccountId) return;
         setLoading(true);
         setError(null);
+        setUnlockSuccess(false);
         try {
-            // Try to unlock the token, but continue even if it fails
-            const unlockResult = await handleUnlock();
-            if (unlockResult) {
-                setUnlockSuccess(true);
-            }
-
-            // Get the current contract balance
-            let depositBalance;
-            try {
-                depositBalance = await getDepositBalance();
-            } catch (err) {
-                setError('Failed to fetch balance. Please try again.');
-                return;
-            }
-
-            if (!depositBalance || depositBalance === "0") {
-                setError('No balance available to withdraw');
-                return;
-            }
-
-            // Make a call to the intents contract to withdraw and bridge the funds
+            // Make a single call to the contract to unlock the funds and bridge them to the address
             // Use signAndSendTransaction so we can get the transaction hash
             const outcome = await wallet.signAndSendTransaction({
-                receiverId: "intents.near",
+                receiverId: process.env.NEXT_PUBLIC_CONTRACT_ID,
                 actions: [
                     {
                         type: "FunctionCall",
                         params: {
-                            methodName: "ft_withdraw",
+                            methodName: "withdraw_to_chain",
                             args: {
-                                token: selectedToken.near_token_id,
-                                receiver_id: selectedToken.near_token_id,
-                                amount: depositBalance,
-                                memo: `WITHDRAW_TO:${address}`,
+                                token_id: selectedToken.intents_token_id,
+                                address,
                             },
                             gas: "100000000000000",
                             deposit: "1",
@@ -115,11 +46,12 @@ export default function UnlockWithdrawToken({ selectedToken, onWithdraw }) {
                     },
                 ],
             });
+            setUnlockSuccess(true);
             onWithdraw(outcome.transaction.hash);
             setAddress("");
-            setUnlockSuccess(false);
         } catch (err) {
             setError(err.message || 'Error making withdrawal');
+            console.error('Withdrawal error:', err);
         } finally {
             setLoading(false);
         }
@@ -149,7 +81,7 @@ export default function UnlockWithdrawToken({ selectedToken, onWithdraw }) {
                 )}
                 {unlockSuccess && (
                     <div className="text-green-600 bg-green-50 p-2 rounded border border-green-200">
-                        Token unlocked successfully! Proceeding with withdrawal...
+                        Token unlocked and withdrawal submitted!
                     </div>
                 )}
                 <button